@@ -0,0 +1,106 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use tokio::io::{AsyncRead, ReadBuf};
+
+use crate::Utf8Stream;
+
+const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Utf8AsyncReader pulls bytes from any implementor of tokio's
+/// [`AsyncRead`] into an [Utf8Stream](crate::Utf8Stream) as it gets
+/// polled, yielding complete extended grapheme clusters as a
+/// [`futures_core::Stream`].
+///
+/// This is the asynchronous counterpart of
+/// [Utf8Reader](crate::Utf8Reader) and is only available with the
+/// `tokio` cargo feature.
+///
+/// ## Example
+///
+/// ```
+/// use futures::StreamExt;
+/// use utf8_stream::Utf8Stream;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let reader = Utf8Stream::from_async_reader("red❤️heart".as_bytes());
+/// assert_eq!(
+///     reader.map(Result::unwrap).collect::<Vec<String>>().await,
+///     vec!["r", "e", "d", "❤️", "h", "e", "a", "r", "t"]
+/// );
+/// # })
+/// ```
+#[derive(Debug)]
+pub struct Utf8AsyncReader<R: AsyncRead + Unpin> {
+    reader: R,
+    stream: Utf8Stream<'static>,
+    chunk: Vec<u8>,
+    eof: bool,
+}
+
+impl<R: AsyncRead + Unpin> Utf8AsyncReader<R> {
+    /// Creates a new [Utf8AsyncReader](Self) reading chunks of 8 KiB
+    pub fn new(reader: R) -> Utf8AsyncReader<R> {
+        Utf8AsyncReader::with_chunk_size(DEFAULT_CHUNK_SIZE, reader)
+    }
+
+    /// Creates a new [Utf8AsyncReader](Self) reading at most `size`
+    /// bytes at a time
+    pub fn with_chunk_size(size: usize, reader: R) -> Utf8AsyncReader<R> {
+        Utf8AsyncReader {
+            reader,
+            stream: Utf8Stream::default(),
+            chunk: vec![0; size.max(1)],
+            eof: false,
+        }
+    }
+
+    /// Returns the buffered [Utf8Stream](crate::Utf8Stream)
+    pub fn stream(&self) -> &Utf8Stream<'static> {
+        &self.stream
+    }
+
+    /// Returns a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Unwraps this [Utf8AsyncReader](Self), returning the underlying
+    /// reader. Buffered data that was not yet polled is lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for Utf8AsyncReader<R> {
+    type Item = std::io::Result<String>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<std::io::Result<String>>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(cluster) = this.stream.next_complete(this.eof) {
+                return Poll::Ready(Some(Ok(cluster)));
+            }
+            if this.eof {
+                return Poll::Ready(None);
+            }
+            this.stream.discard_consumed();
+            let mut buf = ReadBuf::new(&mut this.chunk);
+            match Pin::new(&mut this.reader).poll_read(cx, &mut buf) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(error)) => return Poll::Ready(Some(Err(error))),
+                Poll::Ready(Ok(())) if buf.filled().is_empty() => {
+                    this.eof = true;
+                    this.stream.finish()?;
+                }
+                Poll::Ready(Ok(())) => {
+                    this.stream.push_bytes(buf.filled())?;
+                }
+            }
+        }
+    }
+}
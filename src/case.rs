@@ -0,0 +1,151 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::decompose_canonical;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// CaseLocale selects the language-sensitive special casing rules
+/// applied by the case mapping transforms of a
+/// [Utf8Stream](crate::Utf8Stream), such as
+/// [`to_lowercase`](crate::Utf8Stream::to_lowercase) and
+/// [`case_fold`](crate::Utf8Stream::case_fold).
+///
+/// ## Example
+///
+/// ```
+/// use utf8_stream::{CaseLocale, Utf8Stream};
+///
+/// let mut stream = Utf8Stream::new("DİYARBAKIR");
+/// assert_eq!(stream.to_lowercase().as_str(), "di\u{307}yarbakir");
+///
+/// stream.set_case_locale(CaseLocale::Turkic);
+/// assert_eq!(stream.to_lowercase().as_str(), "diyarbakır");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CaseLocale {
+    /// The language-neutral mappings of the Unicode Character
+    /// Database
+    #[default]
+    Neutral,
+    /// The special casing of Turkish and Azerbaijani, which map `I`
+    /// to the dotless `ı` and `i` to the dotted `İ`
+    Turkic,
+}
+
+impl CaseLocale {
+    /// replaces the characters whose mappings under the locale differ
+    /// from the language-neutral ones by characters the neutral
+    /// mappings of `std` take to the same result
+    fn tailor_lowercase(self, string: &str) -> String {
+        match self {
+            CaseLocale::Neutral => string.to_string(),
+            CaseLocale::Turkic => {
+                let mut tailored = String::with_capacity(string.len());
+                let mut chars = string.chars().peekable();
+                while let Some(c) = chars.next() {
+                    match c {
+                        'I' if chars.peek() == Some(&'\u{307}') => {
+                            chars.next();
+                            tailored.push('i');
+                        }
+                        'I' => tailored.push('ı'),
+                        'İ' => tailored.push('i'),
+                        c => tailored.push(c),
+                    }
+                }
+                tailored
+            }
+        }
+    }
+
+    fn tailor_uppercase(self, string: &str) -> String {
+        match self {
+            CaseLocale::Neutral => string.to_string(),
+            CaseLocale::Turkic => string.replace('i', "İ"),
+        }
+    }
+}
+
+pub(crate) fn to_lowercase(string: &str, locale: CaseLocale) -> String {
+    locale.tailor_lowercase(string).to_lowercase()
+}
+
+pub(crate) fn to_uppercase(string: &str, locale: CaseLocale) -> String {
+    locale.tailor_uppercase(string).to_uppercase()
+}
+
+/// titlecases the first grapheme cluster of each word and lowercases
+/// the rest
+pub(crate) fn to_titlecase(string: &str, locale: CaseLocale) -> String {
+    let mut titlecased = String::with_capacity(string.len());
+    for word in string.split_word_bounds() {
+        match word.graphemes(true).next() {
+            Some(first) if word.chars().any(char::is_alphanumeric) => {
+                let mut chars = first.chars();
+                if let Some(c) = chars.next() {
+                    push_titlecase(&mut titlecased, c, locale);
+                }
+                titlecased.push_str(chars.as_str());
+                // lowercases the whole word such that a final sigma
+                // keeps the context of the letters before it
+                let lowercase = to_lowercase(word, locale);
+                let rest = lowercase
+                    .strip_prefix(&to_lowercase(first, locale))
+                    .unwrap_or(&lowercase);
+                titlecased.push_str(rest);
+            }
+            _ => titlecased.push_str(word),
+        }
+    }
+    titlecased
+}
+
+fn push_titlecase(titlecased: &mut String, c: char, locale: CaseLocale) {
+    match c {
+        'i' if locale == CaseLocale::Turkic => titlecased.push('İ'),
+        '\u{1c4}'..='\u{1c6}' => titlecased.push('\u{1c5}'),
+        '\u{1c7}'..='\u{1c9}' => titlecased.push('\u{1c8}'),
+        '\u{1ca}'..='\u{1cc}' => titlecased.push('\u{1cb}'),
+        '\u{1f1}'..='\u{1f3}' => titlecased.push('\u{1f2}'),
+        'ŉ' => titlecased.push_str("\u{2bc}N"),
+        // greek vowels with ypogegrammeni titlecase to a capital with
+        // prosgegrammeni rather than to a capital followed by iota
+        '\u{1f80}'..='\u{1ffc}' if has_ypogegrammeni(c) => {
+            let mut decomposed = String::new();
+            decompose_canonical(c, |c| decomposed.push(c));
+            let mut chars = decomposed.chars();
+            let base = chars.next().into_iter().flat_map(char::to_uppercase);
+            titlecased.extend(base.chain(chars).nfc());
+        }
+        c => {
+            let mut uppercase = c.to_uppercase();
+            titlecased.extend(uppercase.next());
+            titlecased.extend(uppercase.flat_map(char::to_lowercase));
+        }
+    }
+}
+
+fn has_ypogegrammeni(c: char) -> bool {
+    let mut found = false;
+    decompose_canonical(c, |c| found |= c == '\u{345}');
+    found
+}
+
+/// full case folding as per the `C` and `F` entries of
+/// `CaseFolding.txt`, or the `T` entries under the turkic locale
+pub(crate) fn case_fold(string: &str, locale: CaseLocale) -> String {
+    let mut folded = String::with_capacity(string.len());
+    for c in string.chars() {
+        match c {
+            'I' if locale == CaseLocale::Turkic => folded.push('ı'),
+            'İ' if locale == CaseLocale::Turkic => folded.push('i'),
+            // dotless i folds to itself even though it uppercases to I
+            'ı' => folded.push('ı'),
+            'ẞ' => folded.push_str("ss"),
+            // cherokee folds to its uppercase letters for stability
+            '\u{13a0}'..='\u{13f5}' | '\u{13f8}'..='\u{13fd}' | '\u{ab70}'..='\u{abbf}' => {
+                folded.extend(c.to_uppercase())
+            }
+            c => folded.extend(c.to_uppercase().flat_map(char::to_lowercase)),
+        }
+    }
+    folded
+}
@@ -0,0 +1,83 @@
+use std::fmt::{Display, Formatter};
+
+/// Errors reported by [Utf8Stream](crate::Utf8Stream)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Utf8StreamError {
+    /// An invalid utf8 sequence of `length` bytes was found at byte
+    /// `offset` of the stream.
+    InvalidSequence { offset: usize, length: usize },
+    /// The stream ended in the middle of a multi-byte sequence
+    /// starting at byte `offset` of the stream.
+    IncompleteTail { offset: usize, bytes: Vec<u8> },
+    /// The byte `index` is out of range for a stream of `length`
+    /// bytes.
+    IndexOutOfRange { index: usize, length: usize },
+    /// The byte `index` is not at the boundary of a char.
+    NotCharBoundary { index: usize },
+    /// Allocating a buffer of `size` bytes failed.
+    AllocationFailure { size: usize },
+    /// Holding `length` bytes would exceed the `capacity` of a
+    /// bounded stream.
+    CapacityExceeded { capacity: usize, length: usize },
+    /// A record of at least `length` bytes exceeds the `limit` of a
+    /// delimiter-framed stream.
+    RecordTooLong { limit: usize, length: usize },
+    /// A frame of `length` bytes exceeds the `limit` of a
+    /// [FrameDecoder](crate::FrameDecoder).
+    FrameTooLong { limit: usize, length: usize },
+    /// The framing of the data is malformed at byte `offset` of the
+    /// input of a [FrameDecoder](crate::FrameDecoder).
+    MalformedFrame { offset: usize },
+}
+
+impl Display for Utf8StreamError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Utf8StreamError::InvalidSequence { offset, length } => write!(
+                f,
+                "invalid utf-8 sequence of {length} bytes at offset {offset}"
+            ),
+            Utf8StreamError::IncompleteTail { offset, bytes } => write!(
+                f,
+                "incomplete utf-8 byte sequence {bytes:?} at offset {offset}"
+            ),
+            Utf8StreamError::IndexOutOfRange { index, length } => write!(
+                f,
+                "byte index {index} is out of range for stream of {length} bytes"
+            ),
+            Utf8StreamError::NotCharBoundary { index } => {
+                write!(f, "byte index {index} is not a char boundary")
+            }
+            Utf8StreamError::AllocationFailure { size } => {
+                write!(f, "failed to allocate {size} bytes")
+            }
+            Utf8StreamError::CapacityExceeded { capacity, length } => write!(
+                f,
+                "{length} bytes exceed the bounded stream capacity of {capacity} bytes"
+            ),
+            Utf8StreamError::RecordTooLong { limit, length } => write!(
+                f,
+                "record of {length} bytes exceeds the limit of {limit} bytes"
+            ),
+            Utf8StreamError::FrameTooLong { limit, length } => write!(
+                f,
+                "frame of {length} bytes exceeds the limit of {limit} bytes"
+            ),
+            Utf8StreamError::MalformedFrame { offset } => {
+                write!(f, "malformed frame at offset {offset}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Utf8StreamError {}
+
+impl From<Utf8StreamError> for std::io::Error {
+    fn from(error: Utf8StreamError) -> std::io::Error {
+        let kind = match error {
+            Utf8StreamError::CapacityExceeded { .. } => std::io::ErrorKind::WouldBlock,
+            _ => std::io::ErrorKind::InvalidData,
+        };
+        std::io::Error::new(kind, error)
+    }
+}
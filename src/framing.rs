@@ -0,0 +1,459 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::index::ClusterIndex;
+use crate::{InvalidPolicy, Utf8Stream, Utf8StreamError};
+
+/// Iterator over the delimiter-framed records of a
+/// [Utf8Stream](crate::Utf8Stream), see
+/// [`Utf8Stream::records`](crate::Utf8Stream::records).
+///
+/// Each record is yielded, without its delimiter, as soon as the
+/// delimiter that ends it is in the stream, while a trailing record
+/// whose delimiter has not arrived yet stays buffered in the stream
+/// for the data pushed later to complete it. The records consumed
+/// are dropped from the stream once the iterator is dropped.
+///
+/// ## Example
+///
+/// ```
+/// use utf8_stream::Utf8Stream;
+///
+/// let mut stream = Utf8Stream::new("red\n❤️\nhe");
+/// let records: Result<Vec<String>, _> = stream.records("\n").collect();
+/// assert_eq!(records.unwrap(), vec!["red", "❤️"]);
+/// assert_eq!(stream.as_str(), "he");
+///
+/// stream.push("art\n");
+/// assert_eq!(stream.records("\n").next(), Some(Ok("heart".to_string())));
+/// assert_eq!(stream.as_str(), "");
+/// ```
+#[derive(Debug)]
+pub struct Records<'a, 'g> {
+    stream: &'a mut Utf8Stream<'g>,
+    delimiter: &'a str,
+    limit: Option<usize>,
+    /// whether a record ended within a cluster, such that the
+    /// clusters left in the stream have to be segmented again
+    split_cluster: bool,
+}
+
+impl<'a, 'g> Records<'a, 'g> {
+    pub(crate) fn new(stream: &'a mut Utf8Stream<'g>, delimiter: &'a str) -> Records<'a, 'g> {
+        Records {
+            stream,
+            delimiter,
+            limit: None,
+            split_cluster: false,
+        }
+    }
+
+    /// Limits records to `limit` bytes, excluding the delimiter.
+    ///
+    /// A longer record is reported as a
+    /// [`RecordTooLong`](crate::Utf8StreamError::RecordTooLong) error
+    /// as soon as it is known to exceed the limit, even before its
+    /// delimiter arrives, and is discarded up to and including its
+    /// delimiter such that the stream never buffers more than `limit`
+    /// bytes of it.
+    ///
+    /// ```
+    /// use utf8_stream::{Utf8Stream, Utf8StreamError};
+    ///
+    /// let mut stream = Utf8Stream::new("red\nfirefighter");
+    /// let mut records = stream.records("\n").max_length(8);
+    /// assert_eq!(records.next(), Some(Ok("red".to_string())));
+    /// assert_eq!(
+    ///     records.next(),
+    ///     Some(Err(Utf8StreamError::RecordTooLong { limit: 8, length: 11 }))
+    /// );
+    /// assert_eq!(records.next(), None);
+    /// ```
+    pub fn max_length(mut self, limit: usize) -> Records<'a, 'g> {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the buffered part of the record whose delimiter has not
+    /// arrived yet
+    pub fn remainder(&self) -> &str {
+        if self.stream.discarding_record {
+            ""
+        } else {
+            self.stream.remainder()
+        }
+    }
+
+    /// number of bytes at the start of `remainder`, which holds no
+    /// delimiter, that a delimiter arriving later cannot start within
+    fn undelimited(&self, remainder: &str) -> usize {
+        let mut length = remainder.len().saturating_sub(self.delimiter.len() - 1);
+        while !remainder.is_char_boundary(length) {
+            length -= 1;
+        }
+        length
+    }
+
+    /// moves the cursor of the stream past the first `length` bytes of
+    /// its remainder
+    fn consume(&mut self, length: usize) {
+        let remainder = self.stream.remainder();
+        let consumed = &remainder[..length];
+        if let Some((last, _)) = consumed.grapheme_indices(true).next_back() {
+            let cluster = remainder[last..].graphemes(true).next().unwrap_or_default();
+            self.split_cluster |= last + cluster.len() != length;
+        }
+        let stream = &mut *self.stream;
+        let start = stream.index;
+        for cluster in stream.buffer[start..start + length].graphemes(true) {
+            stream.location.advance(cluster);
+            stream.clusters.front += 1;
+        }
+        stream.index += length;
+    }
+}
+
+impl Iterator for Records<'_, '_> {
+    type Item = Result<String, Utf8StreamError>;
+
+    fn next(&mut self) -> Option<Result<String, Utf8StreamError>> {
+        if self.delimiter.is_empty() {
+            return None;
+        }
+        loop {
+            let remainder = self.stream.remainder();
+            let found = remainder.find(self.delimiter);
+            if self.stream.discarding_record {
+                match found {
+                    Some(position) => {
+                        self.consume(position + self.delimiter.len());
+                        self.stream.discarding_record = false;
+                        continue;
+                    }
+                    None => {
+                        self.consume(self.undelimited(remainder));
+                        return None;
+                    }
+                }
+            }
+            let length = found.unwrap_or_else(|| self.undelimited(remainder));
+            return match self.limit {
+                Some(limit) if length > limit => {
+                    match found {
+                        Some(position) => self.consume(position + self.delimiter.len()),
+                        None => {
+                            self.consume(length);
+                            self.stream.discarding_record = true;
+                        }
+                    }
+                    Some(Err(Utf8StreamError::RecordTooLong { limit, length }))
+                }
+                _ => {
+                    let position = found?;
+                    let record = remainder[..position].to_string();
+                    self.consume(position + self.delimiter.len());
+                    Some(Ok(record))
+                }
+            };
+        }
+    }
+}
+
+impl Drop for Records<'_, '_> {
+    fn drop(&mut self) {
+        let stream = &mut *self.stream;
+        stream.discard_consumed();
+        if self.split_cluster {
+            let back = stream.as_str()[stream.end()..].graphemes(true).count();
+            stream.clusters = ClusterIndex::new(stream.as_str());
+            stream.clusters.back = back;
+        }
+    }
+}
+
+/// Framing selects how a [FrameDecoder](crate::FrameDecoder) tells
+/// apart the text frames of its input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Framing {
+    /// Frames encoded as [netstrings](https://cr.yp.to/proto/netstrings.txt),
+    /// i.e. the decimal length of the frame in bytes, a colon, the
+    /// frame and a comma: `5:heart,`
+    Netstring,
+    /// Frames preceded by their length in bytes as a big-endian `u32`
+    LengthPrefixed,
+}
+
+/// most digits of the length of a netstring, those of `usize::MAX`
+const NETSTRING_DIGITS: usize = usize::MAX.ilog10() as usize + 1;
+
+impl Framing {
+    /// Returns `frame` encoded as per the framing
+    ///
+    /// ```
+    /// use utf8_stream::Framing;
+    ///
+    /// assert_eq!(Framing::Netstring.encode("❤️").unwrap(), b"6:\xe2\x9d\xa4\xef\xb8\x8f,");
+    /// assert_eq!(Framing::LengthPrefixed.encode("red").unwrap(), b"\0\0\0\x03red");
+    /// ```
+    pub fn encode(self, frame: &str) -> Result<Vec<u8>, Utf8StreamError> {
+        match self {
+            Framing::Netstring => {
+                let length = frame.len().to_string();
+                let mut encoded = Vec::with_capacity(length.len() + frame.len() + 2);
+                encoded.extend_from_slice(length.as_bytes());
+                encoded.push(b':');
+                encoded.extend_from_slice(frame.as_bytes());
+                encoded.push(b',');
+                Ok(encoded)
+            }
+            Framing::LengthPrefixed => {
+                let length =
+                    u32::try_from(frame.len()).map_err(|_| Utf8StreamError::FrameTooLong {
+                        limit: u32::MAX as usize,
+                        length: frame.len(),
+                    })?;
+                let mut encoded = Vec::with_capacity(frame.len() + 4);
+                encoded.extend_from_slice(&length.to_be_bytes());
+                encoded.extend_from_slice(frame.as_bytes());
+                Ok(encoded)
+            }
+        }
+    }
+}
+
+/// FrameDecoder splits bytes pushed into it in chunks of any size into
+/// the text frames encoded within them as per its
+/// [Framing](crate::Framing), yielding each frame once it is complete.
+///
+/// Each frame is decoded by a [Utf8Stream](crate::Utf8Stream) as per
+/// [`push_bytes`](crate::Utf8Stream::push_bytes) and
+/// [`finish`](crate::Utf8Stream::finish), such that invalid utf8 is
+/// handled as per the [InvalidPolicy](crate::InvalidPolicy) set by
+/// [`policy`](Self::policy). With
+/// [InvalidPolicy::Strict](crate::InvalidPolicy::Strict) a frame that
+/// is not valid utf8 is reported as an
+/// [`InvalidSequence`](crate::Utf8StreamError::InvalidSequence) or
+/// [`IncompleteTail`](crate::Utf8StreamError::IncompleteTail) error
+/// carrying the offset within the input of the decoder. A frame
+/// longer than the limit set by [`max_length`](Self::max_length) is
+/// reported as a [`FrameTooLong`](crate::Utf8StreamError::FrameTooLong)
+/// error, and one whose replacements or escapes do not fit into the
+/// limit as a
+/// [`CapacityExceeded`](crate::Utf8StreamError::CapacityExceeded)
+/// error, as a [bounded](crate::Utf8Stream::with_capacity_bounded)
+/// stream does. The decoder moves on to the next frame after any of
+/// these errors, and discards the bytes of a frame longer than the
+/// limit as they arrive rather than buffer them.
+///
+/// Input that breaks the framing is reported as a
+/// [`MalformedFrame`](crate::Utf8StreamError::MalformedFrame) error,
+/// after which the decoder cannot tell where the next frame starts and
+/// yields nothing else.
+///
+/// ## Example
+///
+/// ```
+/// use utf8_stream::{FrameDecoder, Framing};
+///
+/// let mut decoder = FrameDecoder::new(Framing::Netstring);
+/// decoder.push(b"3:red,6:\xe2\x9d\xa4");
+/// assert_eq!(decoder.next(), Some(Ok("red".to_string())));
+/// assert_eq!(decoder.next(), None);
+///
+/// decoder.push(b"\xef\xb8\x8f,");
+/// assert_eq!(decoder.next(), Some(Ok("❤️".to_string())));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameDecoder {
+    framing: Framing,
+    /// decodes the frames, bounded to the limit if any
+    frame: Utf8Stream<'static>,
+    buffer: Vec<u8>,
+    /// offset within the input of the first byte of the buffer
+    offset: usize,
+    /// bytes left to discard of a frame that exceeds the limit
+    skip: usize,
+    malformed: bool,
+}
+
+impl FrameDecoder {
+    /// Creates a new [FrameDecoder](Self) for the given
+    /// [Framing](crate::Framing)
+    pub fn new(framing: Framing) -> FrameDecoder {
+        FrameDecoder {
+            framing,
+            frame: Utf8Stream::default(),
+            buffer: Vec::new(),
+            offset: 0,
+            skip: 0,
+            malformed: false,
+        }
+    }
+
+    /// Limits frames to `limit` bytes
+    ///
+    /// ```
+    /// use utf8_stream::{FrameDecoder, Framing, Utf8StreamError};
+    ///
+    /// let mut decoder = FrameDecoder::new(Framing::LengthPrefixed).max_length(4);
+    /// decoder.push(b"\0\0\0\x0bfirefighter\0\0\0\x03red");
+    /// assert_eq!(
+    ///     decoder.next(),
+    ///     Some(Err(Utf8StreamError::FrameTooLong { limit: 4, length: 11 }))
+    /// );
+    /// assert_eq!(decoder.next(), Some(Ok("red".to_string())));
+    /// ```
+    pub fn max_length(mut self, limit: usize) -> FrameDecoder {
+        self.frame.bound = Some(limit);
+        self
+    }
+
+    /// Sets how invalid utf8 within frames is handled
+    ///
+    /// ```
+    /// use utf8_stream::{FrameDecoder, Framing, InvalidPolicy, Utf8StreamError};
+    ///
+    /// let mut decoder = FrameDecoder::new(Framing::Netstring);
+    /// decoder.push(b"4:re\xffd,");
+    /// assert_eq!(decoder.next(), Some(Ok("re\u{fffd}d".to_string())));
+    ///
+    /// let mut decoder = FrameDecoder::new(Framing::Netstring).policy(InvalidPolicy::Strict);
+    /// decoder.push(b"4:re\xffd,");
+    /// assert_eq!(
+    ///     decoder.next(),
+    ///     Some(Err(Utf8StreamError::InvalidSequence { offset: 4, length: 1 }))
+    /// );
+    /// ```
+    pub fn policy(mut self, policy: InvalidPolicy) -> FrameDecoder {
+        self.frame.set_policy(policy);
+        self
+    }
+
+    /// Returns the [Framing](crate::Framing) of the decoder
+    pub fn framing(&self) -> Framing {
+        self.framing
+    }
+
+    /// Returns the buffered bytes of the frame not yet complete
+    pub fn pending(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Pushes more bytes into the decoder
+    pub fn push(&mut self, bytes: &[u8]) {
+        if self.malformed {
+            return;
+        }
+        let skipped = self.skip.min(bytes.len());
+        self.skip -= skipped;
+        self.offset += skipped;
+        self.buffer.extend_from_slice(&bytes[skipped..]);
+    }
+
+    /// drops the first `length` bytes of the buffer, along with the
+    /// `skip` bytes that follow them as they arrive
+    fn consume(&mut self, length: usize, skip: usize) {
+        let skipped = skip.min(self.buffer.len() - length);
+        self.buffer.drain(..length + skipped);
+        self.offset += length + skipped;
+        self.skip = skip - skipped;
+    }
+
+    fn malformed(&mut self, index: usize) -> Utf8StreamError {
+        self.malformed = true;
+        self.buffer.clear();
+        Utf8StreamError::MalformedFrame {
+            offset: self.offset + index,
+        }
+    }
+
+    /// parses the header of the frame at the start of the buffer into
+    /// the length of the header and the length of the frame
+    fn header(&mut self) -> Option<Result<(usize, usize), Utf8StreamError>> {
+        match self.framing {
+            Framing::LengthPrefixed => {
+                let prefix = self.buffer.first_chunk::<4>()?;
+                Some(Ok((4, u32::from_be_bytes(*prefix) as usize)))
+            }
+            Framing::Netstring => {
+                let digits = self
+                    .buffer
+                    .iter()
+                    .take(NETSTRING_DIGITS + 1)
+                    .take_while(|byte| byte.is_ascii_digit())
+                    .count();
+                let leading_zero = digits > 1 && self.buffer[0] == b'0';
+                if digits > NETSTRING_DIGITS || leading_zero {
+                    return Some(Err(self.malformed(0)));
+                }
+                match self.buffer.get(digits) {
+                    None => None,
+                    Some(b':') if digits > 0 => {
+                        let length =
+                            self.buffer[..digits]
+                                .iter()
+                                .try_fold(0usize, |length, digit| {
+                                    length
+                                        .checked_mul(10)?
+                                        .checked_add(usize::from(digit - b'0'))
+                                });
+                        match length {
+                            Some(length) => Some(Ok((digits + 1, length))),
+                            None => Some(Err(self.malformed(0))),
+                        }
+                    }
+                    Some(_) => Some(Err(self.malformed(digits))),
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for FrameDecoder {
+    type Item = Result<String, Utf8StreamError>;
+
+    fn next(&mut self) -> Option<Result<String, Utf8StreamError>> {
+        if self.malformed {
+            return None;
+        }
+        let (header, length) = match self.header()? {
+            Ok(header) => header,
+            Err(error) => return Some(Err(error)),
+        };
+        let trailer = usize::from(self.framing == Framing::Netstring);
+        if let Some(limit) = self.frame.capacity_bound().filter(|&limit| length > limit) {
+            self.consume(header, length.saturating_add(trailer));
+            return Some(Err(Utf8StreamError::FrameTooLong { limit, length }));
+        }
+        let end = header.saturating_add(length);
+        if self.buffer.len() < end.saturating_add(trailer) {
+            return None;
+        }
+        if trailer > 0 && self.buffer[end] != b',' {
+            return Some(Err(self.malformed(end)));
+        }
+        let base = self.offset + header;
+        self.frame.clear();
+        let frame = self
+            .frame
+            .push_bytes(&self.buffer[header..end])
+            .and_then(|()| self.frame.finish())
+            .map(|()| self.frame.as_str().to_string())
+            .map_err(|error| match error {
+                Utf8StreamError::InvalidSequence { offset, length } => {
+                    Utf8StreamError::InvalidSequence {
+                        offset: base + offset,
+                        length,
+                    }
+                }
+                Utf8StreamError::IncompleteTail { offset, bytes } => {
+                    Utf8StreamError::IncompleteTail {
+                        offset: base + offset,
+                        bytes,
+                    }
+                }
+                error => error,
+            });
+        self.consume(end + trailer, 0);
+        Some(frame)
+    }
+}
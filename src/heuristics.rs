@@ -1,98 +1,53 @@
+use unicode_segmentation::GraphemeCursor;
+
 use crate::Utf8Stream;
 
-use crate::{
-    get_byte_at_index, get_byte_slice_of, get_str_slice_of,
-    is_not_ascii_byte,
-};
-/// heuristic function to retrieve human-friendly utf8 strings from a
-/// [Utf8Stream's](crate::Utf8Stream) internal data.
-pub fn get_utf8_at_index<'g>(stream: &Utf8Stream, index: usize) -> (&'g str, usize, usize, usize) {
-    let length = stream.length;
-    let ptr = stream.ptr;
-    if length == 0 {
+/// retrieves the extended grapheme cluster (as per [Unicode Standard
+/// Annex #29](https://www.unicode.org/reports/tr29/)) that contains
+/// the byte at `index` of a [Utf8Stream's](crate::Utf8Stream)
+/// internal data.
+///
+/// Returns the cluster, the index of its first byte, the index of its
+/// last byte and its length in bytes.
+pub fn get_utf8_at_index<'a>(
+    stream: &'a Utf8Stream,
+    index: usize,
+) -> (&'a str, usize, usize, usize) {
+    let string = stream.as_str();
+    let length = string.len();
+    if index >= length {
         return ("", index, 0, 0);
     }
-    if get_byte_at_index(ptr, index) < 127 {
-        return (get_str_slice_of(ptr, index, 1), index, 1, 1);
-    }
-
-    let requested_index = index;
-    let mut index = index;
-    let max = length - 1;
-    let offset = 0;
-    let mut offset = max - if offset == 0 { 0 } else { offset % max };
-
-    index = length - offset;
-
-    while index > 0 && get_byte_at_index(ptr, index) < 127 {
-        let nidx = index - 1;
-        index = nidx;
-    }
-
-    while index < offset && get_byte_at_index(ptr, index) < 127 {
-        let nidx = index + 1;
-        index = nidx;
+    let mut start = index;
+    while !string.is_char_boundary(start) {
+        start -= 1;
     }
-
-    while offset > index && get_byte_at_index(ptr, offset) < 127 {
-        let noff = offset - 1;
-        offset = noff;
-    }
-    while index > 0
-        && is_not_ascii_byte(get_byte_at_index(ptr, index - 1))
-        && std::str::from_utf8(get_byte_slice_of(ptr, index - 1, offset)).is_err()
-    {
-        index -= 1;
-    }
-    while offset < length
-        && is_not_ascii_byte(get_byte_at_index(ptr, offset + 1))
-        && std::str::from_utf8(get_byte_slice_of(ptr, index, offset + 1)).is_ok()
-    {
-        offset += 1;
-    }
-    let mut count = 0;
-    for index in index..offset + 1 {
-        if is_not_ascii_byte(get_byte_at_index(ptr, index)) {
-            count += 1;
-        }
-    }
-
-    if get_byte_at_index(ptr, count) == 0 {
-        count -= 1;
-    };
-    let bytes = get_byte_slice_of(
-        ptr,
-        index,
-        if get_byte_at_index(ptr, offset) == 0 {
-            get_byte_slice_of(ptr, index, offset);
-            if offset == length && get_byte_at_index(ptr, count) == 0 {
-                count
-            } else {
-                count - 1
-            }
-        } else {
-            count
-        },
-    );
-    if let Ok(c) = std::str::from_utf8(bytes) {
-        (c, index, offset, count)
-    } else {
-        ("", index, offset, count)
+    let mut cursor = GraphemeCursor::new(start, length, true);
+    if !cursor.is_boundary(string, 0).unwrap_or(true) {
+        start = cursor.prev_boundary(string, 0).ok().flatten().unwrap_or(0);
     }
+    let mut cursor = GraphemeCursor::new(start, length, true);
+    let end = cursor
+        .next_boundary(string, 0)
+        .ok()
+        .flatten()
+        .unwrap_or(length);
+    let count = end - start;
+    (&string[start..end], start, end - 1, count)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{assert_get_utf8_at_index, get_utf8_at_index, Utf8Stream};
+    use crate::{Utf8Stream, assert_get_utf8_at_index, get_utf8_at_index};
 
     #[test]
     fn test_pop_utf8_esoteric() {
-        assert_get_utf8_at_index!("👩🏽‍🚒", "👩🏽‍🚒", 0, 15, 16);
+        assert_get_utf8_at_index!("👩🏽‍🚒", "👩🏽‍🚒", 0, 14, 15);
     }
 
     #[test]
     fn test_pop_utf8_single() {
-        assert_get_utf8_at_index!("❤️", "❤️", 0, 6, 6);
+        assert_get_utf8_at_index!("❤️", "❤️", 0, 5, 6);
     }
 
     #[test]
@@ -105,6 +60,28 @@ mod tests {
         assert_get_utf8_at_index!("red❤️heart", "❤️", 3, 8, 6);
     }
 
+    #[test]
+    fn test_combining_mark_after_ascii() {
+        assert_get_utf8_at_index!("cafe\u{301}s", "e\u{301}", 3, 5, 3);
+    }
+
+    #[test]
+    fn test_adjacent_emoji() {
+        assert_get_utf8_at_index!("❤️👩🏽‍🚒", "👩🏽‍🚒", 6, 20, 15);
+    }
+
+    #[test]
+    fn test_regional_indicator_flags() {
+        assert_get_utf8_at_index!("🇧🇷🇵🇹", "🇵🇹", 8, 15, 8);
+    }
+
+    #[test]
+    fn test_index_inside_cluster() {
+        let stream = Utf8Stream::new("red❤️heart");
+        let (string, index, offset, count) = get_utf8_at_index(&stream, 5);
+        assert_eq!((string, index, offset, count), ("❤️", 3, 8, 6));
+    }
+
     #[macro_export]
     macro_rules! assert_get_utf8_at_index {
         ($string:literal, $expected:literal, $index:literal, $offset:literal, $count:literal) => {
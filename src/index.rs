@@ -0,0 +1,153 @@
+use std::cmp::Ordering;
+use std::sync::OnceLock;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// ClusterIndex keeps count of the extended grapheme clusters of a
+/// [Utf8Stream](crate::Utf8Stream) as its data changes, such that the
+/// number of clusters left to iterate is known without rescanning the
+/// data.
+///
+/// The byte offset of every cluster is only indexed once random
+/// access by cluster number is requested, after which it is kept in
+/// sync as well.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct ClusterIndex {
+    /// number of clusters in the stream
+    pub(crate) count: usize,
+    /// byte offset of the last cluster, which is the only one that
+    /// appended data may extend
+    pub(crate) last: usize,
+    /// number of clusters before the front cursor
+    pub(crate) front: usize,
+    /// number of clusters after the back cursor
+    pub(crate) back: usize,
+    boundaries: Boundaries,
+}
+
+/// byte offsets of the first byte of every cluster, built on demand.
+///
+/// Being a cache derived from the data of the stream it never affects
+/// comparisons.
+#[derive(Clone, Debug, Default)]
+struct Boundaries(OnceLock<Vec<usize>>);
+
+impl PartialEq for Boundaries {
+    fn eq(&self, _: &Boundaries) -> bool {
+        true
+    }
+}
+impl Eq for Boundaries {}
+impl PartialOrd for Boundaries {
+    fn partial_cmp(&self, other: &Boundaries) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Boundaries {
+    fn cmp(&self, _: &Boundaries) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl ClusterIndex {
+    /// indexes `string` with both cursors at its ends
+    pub(crate) fn new(string: &str) -> ClusterIndex {
+        let mut index = ClusterIndex::default();
+        index.appended(string, false);
+        index
+    }
+
+    /// updates the index after data got appended to `string`,
+    /// counting the new clusters as consumed from the back when the
+    /// back cursor no longer follows the end of the stream
+    pub(crate) fn appended(&mut self, string: &str, detached_tail: bool) {
+        let previous = usize::from(self.count > 0);
+        let mut boundaries = self.boundaries.0.get_mut();
+        if let Some(boundaries) = &mut boundaries {
+            boundaries.truncate(self.count - previous);
+        }
+        let mut added = 0;
+        let mut last = 0;
+        for (offset, _) in string[self.last..].grapheme_indices(true) {
+            added += 1;
+            last = offset;
+            if let Some(boundaries) = &mut boundaries {
+                boundaries.push(self.last + offset);
+            }
+        }
+        self.last += last;
+        self.count = self.count + added - previous;
+        if detached_tail {
+            self.back += added - previous;
+        }
+    }
+
+    /// updates the index after `string` got truncated, which removed
+    /// `removed` clusters starting at byte offset `start` or cut the
+    /// first of them short
+    pub(crate) fn truncated(&mut self, string: &str, start: usize, removed: usize) {
+        let count = self.count;
+        let kept = usize::from(start < string.len());
+        self.count = self.count - removed + kept;
+        if let Some(boundaries) = self.boundaries.0.get_mut() {
+            boundaries.truncate(self.count);
+        }
+        if kept > 0 {
+            self.last = start;
+            self.appended(string, false);
+        } else {
+            self.last = self.last_boundary(string);
+        }
+        self.back = self.back.saturating_sub(count.saturating_sub(self.count));
+    }
+
+    /// updates the index after the last cluster of `string` got
+    /// removed
+    pub(crate) fn popped(&mut self, string: &str) {
+        self.count -= 1;
+        if let Some(boundaries) = self.boundaries.0.get_mut() {
+            boundaries.pop();
+        }
+        self.last = self.last_boundary(string);
+    }
+
+    /// updates the index after the first `offset` bytes of the stream
+    /// got discarded along with the clusters before the front cursor
+    pub(crate) fn discarded(&mut self, offset: usize) {
+        self.count = self.count.saturating_sub(self.front);
+        self.last = self.last.saturating_sub(offset);
+        self.front = 0;
+        self.boundaries.0.take();
+    }
+
+    /// number of clusters between the front and the back cursors
+    pub(crate) fn remaining(&self) -> usize {
+        self.count.saturating_sub(self.front + self.back)
+    }
+
+    /// number of clusters that start before byte `offset` of `string`
+    pub(crate) fn count_before(&self, string: &str, offset: usize) -> usize {
+        match self.boundaries.0.get() {
+            Some(boundaries) => boundaries.partition_point(|&start| start < offset),
+            None => string[..offset].graphemes(true).count(),
+        }
+    }
+
+    /// byte offsets of the first byte of every cluster of `string`
+    pub(crate) fn boundaries(&self, string: &str) -> &[usize] {
+        self.boundaries.0.get_or_init(|| {
+            string
+                .grapheme_indices(true)
+                .map(|(offset, _)| offset)
+                .collect()
+        })
+    }
+
+    fn last_boundary(&self, string: &str) -> usize {
+        string
+            .grapheme_indices(true)
+            .next_back()
+            .map(|(offset, _)| offset)
+            .unwrap_or_default()
+    }
+}
@@ -4,10 +4,49 @@ mod stream;
 #[doc(inline)]
 pub use stream::Utf8Stream;
 
+mod error;
+pub use error::Utf8StreamError;
+
+mod policy;
+pub use policy::InvalidPolicy;
+
+mod case;
+pub use case::CaseLocale;
+
+mod normalize;
+pub use normalize::{NormalizationForm, Normalizer};
+
+mod search;
+pub use search::{FindIter, Finder, MatchIndices, Split};
+
+#[cfg(feature = "regex")]
+mod regex_search;
+#[cfg(feature = "regex")]
+pub use regex_search::{RegexCaptures, RegexFinder, RegexMatches};
+
+mod matcher;
+pub use matcher::{Match, Matcher};
+
+mod framing;
+pub use framing::{FrameDecoder, Framing, Records};
+
+mod position;
+pub use position::{ColumnUnit, Position};
+
+mod reader;
+pub use reader::Utf8Reader;
+
+#[cfg(feature = "tokio")]
+mod async_reader;
+#[cfg(feature = "tokio")]
+pub use async_reader::Utf8AsyncReader;
+
+mod units;
+pub use units::{Bytes, Chars, Graphemes, Lines, Sentences, Words, Wrap};
+
+mod index;
+
 mod internal;
-pub(crate) use internal::{
-    get_byte_at_index, get_byte_slice_of, get_str_slice_of, is_not_ascii_byte,
-};
 
 mod heuristics;
 pub use heuristics::get_utf8_at_index;
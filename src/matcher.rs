@@ -0,0 +1,287 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use crate::Utf8Stream;
+use crate::search::Anchor;
+
+/// Match is a match of one of the patterns of a
+/// [Matcher](crate::Matcher) in a [Utf8Stream](crate::Utf8Stream)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Match {
+    pattern: usize,
+    start: usize,
+    end: usize,
+    cluster_start: usize,
+    cluster_end: usize,
+}
+
+impl Match {
+    /// Returns the index of the matching pattern in the order the
+    /// patterns were given to [`Matcher::new`](crate::Matcher::new)
+    pub fn pattern(&self) -> usize {
+        self.pattern
+    }
+
+    /// Returns the byte offset of the first byte of the match
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte offset past the last byte of the match
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the byte range of the match
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the number of the first extended grapheme cluster of
+    /// the match
+    pub fn cluster_start(&self) -> usize {
+        self.cluster_start
+    }
+
+    /// Returns the number of the extended grapheme cluster past the
+    /// last one of the match
+    pub fn cluster_end(&self) -> usize {
+        self.cluster_end
+    }
+}
+
+/// a state of the automaton, which is a prefix of one or more
+/// patterns
+#[derive(Clone, Debug, Default)]
+struct Node {
+    /// transitions sorted by byte
+    next: Vec<(u8, usize)>,
+    /// the state of the longest proper suffix that is a prefix of a
+    /// pattern
+    fail: usize,
+    /// the patterns that end at this state, including those reached
+    /// through failure links
+    outputs: Vec<usize>,
+}
+
+impl Node {
+    fn transition(&self, byte: u8) -> Option<usize> {
+        self.next
+            .binary_search_by_key(&byte, |&(byte, _)| byte)
+            .ok()
+            .map(|index| self.next[index].1)
+    }
+}
+
+/// Matcher finds every occurrence of a set of patterns in a
+/// [Utf8Stream](crate::Utf8Stream) as data is pushed into it, with an
+/// Aho-Corasick automaton whose state is kept across pushes such that
+/// matches spanning push boundaries are found and data already
+/// scanned is never scanned again.
+///
+/// Matches may overlap and only count when they start and end on
+/// extended grapheme cluster boundaries. Since data pushed later may
+/// extend the last cluster of the stream, [`find`](Self::find) holds
+/// back the matches that end within it until they are settled, while
+/// [`finish`](Self::finish) reports them once no more data is to come.
+///
+/// Byte offsets refer to [`Utf8Stream::as_str`](crate::Utf8Stream::as_str)
+/// at the time of the call. The matcher keeps its place as consumed
+/// data gets discarded, as [`Finder`](crate::Finder) does, drops the
+/// matches it holds that start in discarded data, and scans again the
+/// data that may now match once data gets cut from the end of the
+/// stream. Empty patterns never match.
+///
+/// ## Example
+///
+/// ```
+/// use utf8_stream::{Matcher, Utf8Stream};
+///
+/// let mut matcher = Matcher::new(["heart", "art", "❤"]);
+/// let mut stream = Utf8Stream::new("red❤️he");
+/// assert_eq!(matcher.find(&stream), None);
+///
+/// stream.push("art❤ ");
+/// let found = matcher.find(&stream).unwrap();
+/// assert_eq!((found.pattern(), found.range()), (0, 9..14));
+/// assert_eq!((found.cluster_start(), found.cluster_end()), (4, 9));
+/// let found = matcher.find(&stream).unwrap();
+/// assert_eq!((found.pattern(), found.range()), (1, 11..14));
+/// let found = matcher.find(&stream).unwrap();
+/// assert_eq!((found.pattern(), found.range()), (2, 14..17));
+/// assert_eq!(matcher.find(&stream), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Matcher {
+    patterns: Vec<String>,
+    nodes: Vec<Node>,
+    state: usize,
+    anchor: Anchor,
+    /// the length of the longest pattern
+    longest: usize,
+    /// the offset up to which the matches found again after a cut
+    /// are held already
+    replayed: usize,
+    held: VecDeque<(usize, usize)>,
+}
+
+impl Matcher {
+    /// Creates a new [Matcher](Self) for `patterns` that starts
+    /// scanning at the beginning of the stream
+    pub fn new<I, P>(patterns: I) -> Matcher
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        let patterns: Vec<String> = patterns
+            .into_iter()
+            .map(|pattern| pattern.as_ref().to_string())
+            .collect();
+        let mut nodes = vec![Node::default()];
+        for (index, pattern) in patterns.iter().enumerate() {
+            if pattern.is_empty() {
+                continue;
+            }
+            let mut state = 0;
+            for &byte in pattern.as_bytes() {
+                state = match nodes[state].transition(byte) {
+                    Some(next) => next,
+                    None => {
+                        let next = nodes.len();
+                        nodes.push(Node::default());
+                        let transitions = &mut nodes[state].next;
+                        let position = transitions.partition_point(|&(b, _)| b < byte);
+                        transitions.insert(position, (byte, next));
+                        next
+                    }
+                };
+            }
+            nodes[state].outputs.push(index);
+        }
+
+        // failure links in breadth-first order, such that the failure
+        // link of a state is complete before those of its children
+        let mut queue: VecDeque<usize> = nodes[0].next.iter().map(|&(_, next)| next).collect();
+        while let Some(state) = queue.pop_front() {
+            for index in 0..nodes[state].next.len() {
+                let (byte, child) = nodes[state].next[index];
+                let mut candidate = nodes[state].fail;
+                let fail = loop {
+                    match nodes[candidate].transition(byte) {
+                        Some(next) => break next,
+                        None if candidate == 0 => break 0,
+                        None => candidate = nodes[candidate].fail,
+                    }
+                };
+                nodes[child].fail = fail;
+                let inherited = nodes[fail].outputs.clone();
+                nodes[child].outputs.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+
+        Matcher {
+            longest: patterns.iter().map(String::len).max().unwrap_or_default(),
+            patterns,
+            nodes,
+            state: 0,
+            anchor: Anchor::default(),
+            replayed: 0,
+            held: VecDeque::new(),
+        }
+    }
+
+    /// Returns the patterns of the matcher
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// Returns the byte offset up to which the stream has been
+    /// scanned, counted from the first byte pushed into the stream like
+    /// [`Position::offset`](crate::Position::offset)
+    pub fn offset(&self) -> usize {
+        self.anchor.offset()
+    }
+
+    /// Returns the next match of the data pushed into `stream` so far
+    /// that ends before its last cluster, which data pushed later may
+    /// still extend
+    pub fn find(&mut self, stream: &Utf8Stream) -> Option<Match> {
+        self.find_before(stream, stream.clusters.last)
+    }
+
+    /// Returns the next match in `stream`, up to the end of its data
+    pub fn finish(&mut self, stream: &Utf8Stream) -> Option<Match> {
+        self.find_before(stream, stream.len())
+    }
+
+    fn find_before(&mut self, stream: &Utf8Stream, to: usize) -> Option<Match> {
+        self.scan(stream);
+        let base = stream.location.base.offset;
+        let boundaries = stream.clusters.boundaries(stream.as_str());
+        let is_boundary =
+            |offset: usize| offset == stream.len() || boundaries.binary_search(&offset).is_ok();
+        while let Some(&(pattern, end)) = self.held.front() {
+            if end > base + to {
+                return None;
+            }
+            self.held.pop_front();
+            let Some(start) = (end - self.patterns[pattern].len()).checked_sub(base) else {
+                continue;
+            };
+            let end = end - base;
+            if is_boundary(start) && is_boundary(end) {
+                return Some(Match {
+                    pattern,
+                    start,
+                    end,
+                    cluster_start: boundaries.partition_point(|&offset| offset < start),
+                    cluster_end: boundaries.partition_point(|&offset| offset < end),
+                });
+            }
+        }
+        None
+    }
+
+    /// runs the automaton over the data pushed since the previous
+    /// scan, holding the matches found in the order they end
+    fn scan(&mut self, stream: &Utf8Stream) {
+        // the state depends on the data before the offset, which is
+        // gone once discarded and scanned again up to a cut from the
+        // `longest` bytes before it
+        let skipped = self.anchor.offset() < stream.location.base.offset;
+        let cut = self.anchor.update(stream, self.longest);
+        if skipped || cut.is_some() {
+            self.state = 0;
+        }
+        if let Some(cut) = cut {
+            self.held.retain(|&(_, end)| end <= cut);
+            self.replayed = cut;
+        }
+        let offset = self.anchor.offset();
+        let data = &stream.as_bytes()[self.anchor.get(stream)..];
+        for (index, &byte) in data.iter().enumerate() {
+            self.state = self.next_state(self.state, byte);
+            let end = offset + index + 1;
+            if end <= self.replayed {
+                continue;
+            }
+            let outputs = &self.nodes[self.state].outputs;
+            self.held
+                .extend(outputs.iter().map(|&pattern| (pattern, end)));
+        }
+        self.anchor.set(stream, stream.len());
+    }
+
+    fn next_state(&self, mut state: usize, byte: u8) -> usize {
+        loop {
+            if let Some(next) = self.nodes[state].transition(byte) {
+                return next;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.nodes[state].fail;
+        }
+    }
+}
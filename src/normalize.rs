@@ -0,0 +1,125 @@
+use unicode_normalization::char::canonical_combining_class;
+use unicode_normalization::{
+    IsNormalized, UnicodeNormalization, is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick,
+};
+
+/// NormalizationForm selects one of the normalization forms of
+/// [Unicode Standard Annex #15](https://www.unicode.org/reports/tr15/)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NormalizationForm {
+    /// Canonical decomposition followed by canonical composition
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+impl NormalizationForm {
+    /// Returns `string` normalized to this form
+    ///
+    /// ```
+    /// use utf8_stream::NormalizationForm;
+    ///
+    /// assert_eq!(NormalizationForm::Nfc.normalize("e\u{301}"), "\u{e9}");
+    /// assert_eq!(NormalizationForm::Nfd.normalize("\u{e9}"), "e\u{301}");
+    /// assert_eq!(NormalizationForm::Nfkc.normalize("ﬁ"), "fi");
+    /// ```
+    pub fn normalize(self, string: &str) -> String {
+        match self {
+            NormalizationForm::Nfc => string.nfc().collect(),
+            NormalizationForm::Nfd => string.nfd().collect(),
+            NormalizationForm::Nfkc => string.nfkc().collect(),
+            NormalizationForm::Nfkd => string.nfkd().collect(),
+        }
+    }
+
+    /// whether text can be split before `c` and both sides be
+    /// normalized on their own, which requires `c` to be a starter
+    /// that neither reorders nor composes with what precedes it
+    fn is_safe_point(self, c: char) -> bool {
+        let c = std::iter::once(c);
+        let quick = match self {
+            NormalizationForm::Nfc => is_nfc_quick(c),
+            NormalizationForm::Nfd => is_nfd_quick(c),
+            NormalizationForm::Nfkc => is_nfkc_quick(c),
+            NormalizationForm::Nfkd => is_nfkd_quick(c),
+        };
+        quick == IsNormalized::Yes
+    }
+}
+
+/// Normalizer normalizes text that arrives in chunks, holding back
+/// the trailing segment of each chunk until a starter that the
+/// segment cannot combine with arrives, such that the concatenation
+/// of its output equals the normalization of the whole text.
+///
+/// A [Utf8Stream](crate::Utf8Stream) applies a Normalizer to the data
+/// pushed into it when created with
+/// [`with_normalization`](crate::Utf8Stream::with_normalization).
+///
+/// ## Example
+///
+/// ```
+/// use utf8_stream::{NormalizationForm, Normalizer};
+///
+/// let mut normalizer = Normalizer::new(NormalizationForm::Nfc);
+/// assert_eq!(normalizer.push("cafe"), "caf");
+/// assert_eq!(normalizer.push("\u{301} au lait"), "\u{e9} au lai");
+/// assert_eq!(normalizer.finish(), "t");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Normalizer {
+    form: NormalizationForm,
+    held: String,
+}
+
+impl Normalizer {
+    /// Creates a new [Normalizer](Self) to the given form
+    pub fn new(form: NormalizationForm) -> Normalizer {
+        Normalizer {
+            form,
+            held: String::new(),
+        }
+    }
+
+    /// Returns the [NormalizationForm](crate::NormalizationForm) of
+    /// the normalizer
+    pub fn form(&self) -> NormalizationForm {
+        self.form
+    }
+
+    /// Returns the text held back until more text arrives
+    pub fn held(&self) -> &str {
+        &self.held
+    }
+
+    /// Normalizes `chunk` along with the text held back from previous
+    /// chunks, returning the normalized text up to the last safe
+    /// point and holding back the rest.
+    pub fn push(&mut self, chunk: &str) -> String {
+        self.held.push_str(chunk);
+        let split = self
+            .held
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| canonical_combining_class(c) == 0 && self.form.is_safe_point(c))
+            .map(|(offset, _)| offset)
+            .unwrap_or_default();
+        let rest = self.held.split_off(split);
+        let ready = std::mem::replace(&mut self.held, rest);
+        self.form.normalize(&ready)
+    }
+
+    /// length of the text [`finish`](Self::finish) would return
+    pub(crate) fn finished_len(&self) -> usize {
+        self.form.normalize(&self.held).len()
+    }
+
+    /// Returns the normalization of the text held back
+    pub fn finish(&mut self) -> String {
+        self.form.normalize(&std::mem::take(&mut self.held))
+    }
+}
@@ -0,0 +1,54 @@
+/// InvalidPolicy determines how a [Utf8Stream](crate::Utf8Stream)
+/// handles invalid utf8 sequences in the bytes pushed into it.
+///
+/// The policy is applied as bytes are ingested (e.g. by
+/// [`push_bytes`](crate::Utf8Stream::push_bytes) and
+/// [`finish`](crate::Utf8Stream::finish)), such that the stream only
+/// ever holds valid utf8 and [`next`](Iterator::next),
+/// [`get`](crate::Utf8Stream::get) and
+/// [`as_str`](crate::Utf8Stream::as_str) never truncate or hide data.
+///
+/// ## Example
+///
+/// ```
+/// use utf8_stream::{InvalidPolicy, Utf8Stream};
+///
+/// let mut stream = Utf8Stream::with_policy(InvalidPolicy::Escape);
+/// stream.push_bytes(b"red\xe2\x9d heart").unwrap();
+/// assert_eq!(stream.as_str(), "red\\xe2\\x9d heart");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InvalidPolicy {
+    /// Rejects invalid sequences with an
+    /// [`InvalidSequence`](crate::Utf8StreamError::InvalidSequence)
+    /// error carrying the byte offset of the sequence within the
+    /// stream. The bytes that follow the invalid sequence are
+    /// discarded.
+    Strict,
+    /// Replaces each maximal subpart of an invalid sequence with
+    /// `U+FFFD REPLACEMENT CHARACTER`, as per the WHATWG Encoding
+    /// Standard.
+    #[default]
+    Replace,
+    /// Drops invalid sequences
+    Skip,
+    /// Replaces each byte of an invalid sequence with its `\xNN`
+    /// escape
+    Escape,
+}
+
+impl InvalidPolicy {
+    /// appends what stands for the invalid `sequence` to `output`,
+    /// which is nothing when strict as the sequence gets rejected
+    pub(crate) fn substitute(self, sequence: &[u8], output: &mut String) {
+        match self {
+            InvalidPolicy::Strict | InvalidPolicy::Skip => {}
+            InvalidPolicy::Replace => output.push(char::REPLACEMENT_CHARACTER),
+            InvalidPolicy::Escape => {
+                for byte in sequence {
+                    output.push_str(&format!("\\x{byte:02x}"));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt::{Display, Formatter};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+const DEFAULT_TAB_WIDTH: usize = 8;
+
+/// Position of the front cursor of a [Utf8Stream](crate::Utf8Stream)
+/// within its input, see [`Utf8Stream::location`](crate::Utf8Stream::location).
+///
+/// Lines and columns are counted from 1 and the column is measured in
+/// the [ColumnUnit](crate::ColumnUnit) of the stream.
+///
+/// ## Example
+///
+/// ```
+/// use utf8_stream::{Position, Utf8Stream};
+///
+/// let mut stream = Utf8Stream::new("red\n❤️heart");
+/// stream.advance_by(5).unwrap();
+/// assert_eq!(stream.location(), Position { line: 2, column: 2, offset: 10 });
+/// assert_eq!(stream.location().to_string(), "2:2");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column number within the line, starting at 1
+    pub column: usize,
+    /// Byte offset within the input
+    pub offset: usize,
+}
+
+impl Default for Position {
+    fn default() -> Position {
+        Position {
+            line: 1,
+            column: 1,
+            offset: 0,
+        }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// ColumnUnit determines what the column of a
+/// [Position](crate::Position) counts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColumnUnit {
+    /// Counts chars
+    Chars,
+    /// Counts extended grapheme clusters
+    #[default]
+    Graphemes,
+    /// Counts the cells the clusters occupy on a terminal, where wide
+    /// characters and emoji take two cells and tabs advance to the
+    /// next tab stop, see
+    /// [`set_tab_width`](crate::Utf8Stream::set_tab_width)
+    Cells,
+}
+
+/// keeps the [Position] of the front cursor of a
+/// [Utf8Stream](crate::Utf8Stream) up to date as it advances over
+/// clusters
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Locator {
+    /// position of the start of the buffer, which moves forward as
+    /// consumed clusters get discarded
+    pub(crate) base: Position,
+    pub(crate) position: Position,
+    pub(crate) unit: ColumnUnit,
+    pub(crate) tab_width: usize,
+    /// whether the last cluster was a `\r` which a `\n` cluster
+    /// pushed later on belongs to
+    after_cr: bool,
+    base_after_cr: bool,
+}
+
+impl Default for Locator {
+    fn default() -> Locator {
+        Locator {
+            base: Position::default(),
+            position: Position::default(),
+            unit: ColumnUnit::default(),
+            tab_width: DEFAULT_TAB_WIDTH,
+            after_cr: false,
+            base_after_cr: false,
+        }
+    }
+}
+
+impl Locator {
+    /// moves the position past `cluster`
+    pub(crate) fn advance(&mut self, cluster: &str) {
+        let position = &mut self.position;
+        position.offset += cluster.len();
+        match cluster {
+            "\n" if self.after_cr => {}
+            "\r" | "\n" | "\r\n" | "\u{2028}" | "\u{2029}" => {
+                position.line += 1;
+                position.column = 1;
+            }
+            "\t" if self.unit == ColumnUnit::Cells => {
+                let tab_width = self.tab_width.max(1);
+                position.column += tab_width - (position.column - 1) % tab_width;
+            }
+            _ => {
+                position.column += match self.unit {
+                    ColumnUnit::Chars => cluster.chars().count(),
+                    ColumnUnit::Graphemes => 1,
+                    ColumnUnit::Cells => cluster.width(),
+                }
+            }
+        }
+        self.after_cr = cluster == "\r";
+    }
+
+    /// recomputes the position from the start of `buffer` up to byte
+    /// `index`
+    pub(crate) fn relocate(&mut self, buffer: &str, index: usize) {
+        self.position = self.base;
+        self.after_cr = self.base_after_cr;
+        for cluster in buffer[..index].graphemes(true) {
+            self.advance(cluster);
+        }
+    }
+
+    /// makes the current position the start of the buffer
+    pub(crate) fn rebase(&mut self) {
+        self.base = self.position;
+        self.base_after_cr = self.after_cr;
+    }
+
+    /// moves the position back to the start of the input
+    pub(crate) fn reset(&mut self) {
+        *self = Locator {
+            unit: self.unit,
+            tab_width: self.tab_width,
+            ..Locator::default()
+        };
+    }
+}
@@ -0,0 +1,115 @@
+use std::io::{ErrorKind, Read};
+use std::iter::{FusedIterator, Iterator};
+
+use crate::Utf8Stream;
+
+const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Utf8Reader pulls bytes from any implementor of [`Read`] into an
+/// [Utf8Stream](crate::Utf8Stream) as it gets iterated, yielding
+/// complete extended grapheme clusters.
+///
+/// Bytes are read in chunks on demand and the clusters already
+/// yielded are discarded before each read, so memory stays bounded by
+/// the chunk size regardless of the length of the input.
+///
+/// ## Example
+///
+/// ```
+/// use utf8_stream::Utf8Stream;
+///
+/// let reader = Utf8Stream::from_reader("red❤️heart".as_bytes());
+/// assert_eq!(
+///     reader.map(Result::unwrap).collect::<Vec<String>>(),
+///     vec!["r", "e", "d", "❤️", "h", "e", "a", "r", "t"]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Utf8Reader<R: Read> {
+    reader: R,
+    stream: Utf8Stream<'static>,
+    chunk: Vec<u8>,
+    eof: bool,
+}
+
+impl<R: Read> Utf8Reader<R> {
+    /// Creates a new [Utf8Reader](Self) reading chunks of 8 KiB
+    pub fn new(reader: R) -> Utf8Reader<R> {
+        Utf8Reader::with_chunk_size(DEFAULT_CHUNK_SIZE, reader)
+    }
+
+    /// Creates a new [Utf8Reader](Self) reading at most `size` bytes
+    /// at a time
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Reader;
+    ///
+    /// let reader = Utf8Reader::with_chunk_size(1, "fire👩🏽‍🚒".as_bytes());
+    /// assert_eq!(
+    ///     reader.map(Result::unwrap).collect::<Vec<String>>(),
+    ///     vec!["f", "i", "r", "e", "👩🏽‍🚒"]
+    /// );
+    /// ```
+    pub fn with_chunk_size(size: usize, reader: R) -> Utf8Reader<R> {
+        Utf8Reader {
+            reader,
+            stream: Utf8Stream::default(),
+            chunk: vec![0; size.max(1)],
+            eof: false,
+        }
+    }
+
+    /// Returns the buffered [Utf8Stream](crate::Utf8Stream)
+    pub fn stream(&self) -> &Utf8Stream<'static> {
+        &self.stream
+    }
+
+    /// Returns a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Unwraps this [Utf8Reader](Self), returning the underlying
+    /// reader. Buffered data that was not yet iterated is lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn fill(&mut self) -> std::io::Result<()> {
+        self.stream.discard_consumed();
+        loop {
+            match self.reader.read(&mut self.chunk) {
+                Ok(0) => {
+                    self.eof = true;
+                    self.stream.finish()?;
+                    return Ok(());
+                }
+                Ok(count) => {
+                    self.stream.push_bytes(&self.chunk[..count])?;
+                    return Ok(());
+                }
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for Utf8Reader<R> {
+    type Item = std::io::Result<String>;
+
+    fn next(&mut self) -> Option<std::io::Result<String>> {
+        loop {
+            if let Some(cluster) = self.stream.next_complete(self.eof) {
+                return Some(Ok(cluster));
+            }
+            if self.eof {
+                return None;
+            }
+            if let Err(error) = self.fill() {
+                return Some(Err(error));
+            }
+        }
+    }
+}
+impl<R: Read> FusedIterator for Utf8Reader<R> {}
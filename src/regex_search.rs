@@ -0,0 +1,243 @@
+use std::iter::{FusedIterator, Iterator};
+
+use regex::{Captures, Match, Regex};
+
+use crate::Utf8Stream;
+use crate::search::{Anchor, Haystack};
+
+/// whether a match from `start` to `end` starts and ends on cluster
+/// boundaries
+fn is_aligned(haystack: &Haystack, start: usize, end: usize) -> bool {
+    haystack.is_boundary(start) && haystack.is_boundary(end)
+}
+
+/// Iterator over the matches of a [Regex](regex::Regex) in a
+/// [Utf8Stream](crate::Utf8Stream) that start and end on extended
+/// grapheme cluster boundaries, see
+/// [`Utf8Stream::regex_find_iter`](crate::Utf8Stream::regex_find_iter).
+#[derive(Clone, Debug)]
+pub struct RegexMatches<'a> {
+    haystack: Haystack<'a>,
+    regex: &'a Regex,
+    from: usize,
+}
+impl<'a> RegexMatches<'a> {
+    pub(crate) fn new(haystack: Haystack<'a>, regex: &'a Regex) -> RegexMatches<'a> {
+        RegexMatches {
+            haystack,
+            regex,
+            from: 0,
+        }
+    }
+}
+impl<'a> Iterator for RegexMatches<'a> {
+    type Item = Match<'a>;
+
+    fn next(&mut self) -> Option<Match<'a>> {
+        let string = self.haystack.as_str();
+        while self.from <= string.len() {
+            let found = self.regex.find_at(string, self.from)?;
+            if is_aligned(&self.haystack, found.start(), found.end()) {
+                self.from = self.haystack.resume(found.start(), found.end());
+                return Some(found);
+            }
+            self.from = self.haystack.next_char(found.start());
+        }
+        None
+    }
+}
+impl FusedIterator for RegexMatches<'_> {}
+
+/// Iterator over the captures of the matches of a
+/// [Regex](regex::Regex) in a [Utf8Stream](crate::Utf8Stream) that
+/// start and end on extended grapheme cluster boundaries, see
+/// [`Utf8Stream::captures_iter`](crate::Utf8Stream::captures_iter).
+#[derive(Clone, Debug)]
+pub struct RegexCaptures<'a> {
+    haystack: Haystack<'a>,
+    regex: &'a Regex,
+    from: usize,
+}
+impl<'a> RegexCaptures<'a> {
+    pub(crate) fn new(haystack: Haystack<'a>, regex: &'a Regex) -> RegexCaptures<'a> {
+        RegexCaptures {
+            haystack,
+            regex,
+            from: 0,
+        }
+    }
+}
+impl<'a> Iterator for RegexCaptures<'a> {
+    type Item = Captures<'a>;
+
+    fn next(&mut self) -> Option<Captures<'a>> {
+        let string = self.haystack.as_str();
+        while self.from <= string.len() {
+            let captures = self.regex.captures_at(string, self.from)?;
+            let found = captures.get(0)?;
+            if is_aligned(&self.haystack, found.start(), found.end()) {
+                self.from = self.haystack.resume(found.start(), found.end());
+                return Some(captures);
+            }
+            self.from = self.haystack.next_char(found.start());
+        }
+        None
+    }
+}
+impl FusedIterator for RegexCaptures<'_> {}
+
+/// RegexFinder matches a [Regex](regex::Regex) against a
+/// [Utf8Stream](crate::Utf8Stream) incrementally, resuming each search
+/// after the previous match such that matches already reported are
+/// not searched again as more data is pushed into the stream.
+///
+/// A match that reaches into the last cluster of the stream could
+/// still grow with the data pushed later, as `\w+` does, so
+/// [`find`](Self::find) holds it back until the data extends past it,
+/// while [`finish`](Self::finish) reports it once no more data is to
+/// come. Patterns whose matches may span any distance, such as
+/// `a.*b`, may still be cut short by a match found before the rest of
+/// the data arrives.
+///
+/// Since a match may start anywhere after the previous one until the
+/// data that completes it arrives, each search rescans the data from
+/// the previous match, which grows with the stream while matches are
+/// rare. Bounding the length of matches with
+/// [`max_match_length`](Self::max_match_length) bounds the rescan to
+/// that many bytes.
+///
+/// Matches are reported in
+/// [`Utf8Stream::as_str`](crate::Utf8Stream::as_str) at the time of the
+/// call. Like [`Finder`](crate::Finder), the finder keeps its place as
+/// consumed data gets discarded and, once data gets cut from the end
+/// of the stream, takes back the matches that end past the cut and
+/// searches again from the last match left, or from
+/// [`max_match_length`](Self::max_match_length) bytes before the cut.
+///
+/// Requires the `regex` feature.
+///
+/// ## Example
+///
+/// ```
+/// use regex::Regex;
+/// use utf8_stream::{RegexFinder, Utf8Stream};
+///
+/// let mut finder = RegexFinder::new(Regex::new(r"\w+").unwrap());
+/// let mut stream = Utf8Stream::new("red he");
+/// assert_eq!(finder.find(&stream).map(|found| found.as_str()), Some("red"));
+/// assert_eq!(finder.find(&stream), None);
+///
+/// stream.push("art ");
+/// assert_eq!(finder.find(&stream).map(|found| found.as_str()), Some("heart"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RegexFinder {
+    regex: Regex,
+    anchor: Anchor,
+    limit: Option<usize>,
+}
+
+impl RegexFinder {
+    /// Creates a new [RegexFinder](Self) for `regex` that starts
+    /// searching at the beginning of the stream
+    pub fn new(regex: Regex) -> RegexFinder {
+        RegexFinder {
+            regex,
+            anchor: Anchor::default(),
+            limit: None,
+        }
+    }
+
+    /// Promises that matches of the regex span at most `limit` bytes,
+    /// such that searches that find nothing resume close to the end of
+    /// the stream rather than rescan it from the previous match.
+    ///
+    /// Matches longer than `limit` may be missed.
+    ///
+    /// ```
+    /// use regex::Regex;
+    /// use utf8_stream::{RegexFinder, Utf8Stream};
+    ///
+    /// let mut finder = RegexFinder::new(Regex::new(r"\d{4}").unwrap()).max_match_length(4);
+    /// let mut stream = Utf8Stream::new("red heart 20");
+    /// assert_eq!(finder.find(&stream), None);
+    /// assert_eq!(finder.offset(), 9);
+    ///
+    /// stream.push("26 ");
+    /// assert_eq!(finder.find(&stream).map(|found| found.as_str()), Some("2026"));
+    /// ```
+    pub fn max_match_length(mut self, limit: usize) -> RegexFinder {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the [Regex](regex::Regex) of the finder
+    pub fn regex(&self) -> &Regex {
+        &self.regex
+    }
+
+    /// Returns the byte offset at which the next search starts,
+    /// counted from the first byte pushed into the stream like
+    /// [`Position::offset`](crate::Position::offset)
+    pub fn offset(&self) -> usize {
+        self.anchor.offset()
+    }
+
+    /// Returns the next match of the regex that ends before the last
+    /// cluster of `stream`, which data pushed later may still extend
+    pub fn find<'a>(&mut self, stream: &'a Utf8Stream) -> Option<Match<'a>> {
+        self.find_before(stream, stream.clusters.last)
+    }
+
+    /// Returns the next match of the regex in `stream`, up to the end
+    /// of its data
+    pub fn finish<'a>(&mut self, stream: &'a Utf8Stream) -> Option<Match<'a>> {
+        self.find_before(stream, stream.len())
+    }
+
+    fn find_before<'a>(&mut self, stream: &'a Utf8Stream, to: usize) -> Option<Match<'a>> {
+        // without a limit, a match taken back by a cut may have
+        // started anywhere after the last match left
+        let reach = self
+            .limit
+            .map_or(usize::MAX, |limit| limit.saturating_sub(1));
+        self.anchor.update(stream, reach);
+        let haystack = stream.haystack();
+        let string = haystack.as_str();
+        let mut offset = self.anchor.get(stream);
+        while offset <= string.len() {
+            let Some(found) = self.regex.find_at(string, offset) else {
+                self.skip_unmatched(stream, offset, string.len());
+                return None;
+            };
+            if found.end() > to {
+                self.skip_unmatched(stream, offset, found.start());
+                return None;
+            }
+            if is_aligned(&haystack, found.start(), found.end()) {
+                let resume = haystack.resume(found.start(), found.end());
+                self.anchor.matched(stream, found.end(), resume);
+                return Some(found);
+            }
+            offset = haystack.next_char(found.start());
+        }
+        self.anchor.set(stream, offset);
+        None
+    }
+
+    /// moves the offset from `offset` towards `before`, given that no
+    /// match starts between them in `stream`: a match that starts
+    /// there once more data arrives has to end past its data and to
+    /// span at most the limit, if any
+    fn skip_unmatched(&mut self, stream: &Utf8Stream, mut offset: usize, before: usize) {
+        if let Some(limit) = self.limit {
+            let string = stream.as_str();
+            let mut skipped = (string.len() + 1).saturating_sub(limit.max(1)).min(before);
+            while !string.is_char_boundary(skipped) {
+                skipped += 1;
+            }
+            offset = offset.max(skipped);
+        }
+        self.anchor.set(stream, offset);
+    }
+}
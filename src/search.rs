@@ -0,0 +1,444 @@
+use std::cmp::Ordering;
+use std::iter::{FusedIterator, Iterator};
+
+use crate::Utf8Stream;
+
+/// the data of a [Utf8Stream](crate::Utf8Stream) along with the byte
+/// offsets of its clusters, such that matches that start or end in
+/// the middle of a cluster can be told apart
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Haystack<'a> {
+    string: &'a str,
+    boundaries: &'a [usize],
+}
+
+impl<'a> Haystack<'a> {
+    pub(crate) fn new(string: &'a str, boundaries: &'a [usize]) -> Haystack<'a> {
+        Haystack { string, boundaries }
+    }
+
+    pub(crate) fn as_str(&self) -> &'a str {
+        self.string
+    }
+
+    pub(crate) fn is_boundary(&self, offset: usize) -> bool {
+        offset == self.string.len() || self.boundaries.binary_search(&offset).is_ok()
+    }
+
+    /// byte offset of the cluster after the one at `offset`, which is
+    /// past the end of the data when `offset` is its length
+    pub(crate) fn next_boundary(&self, offset: usize) -> usize {
+        let next = self.boundaries.partition_point(|&start| start <= offset);
+        match self.boundaries.get(next) {
+            Some(&start) => start,
+            None if offset < self.string.len() => self.string.len(),
+            None => offset + 1,
+        }
+    }
+
+    /// byte offset of the first match of `pattern` that starts at or
+    /// after `from` and ends at or before `to` on cluster boundaries
+    pub(crate) fn find(&self, pattern: &str, from: usize, to: usize) -> Option<usize> {
+        let mut from = from;
+        loop {
+            let start = from + self.string.get(from..to)?.find(pattern)?;
+            let end = start + pattern.len();
+            if self.is_boundary(start) && self.is_boundary(end) {
+                return Some(start);
+            }
+            from = self.next_char(start);
+        }
+    }
+
+    /// byte offset of the last match of `pattern` that ends at or
+    /// before `to` on cluster boundaries
+    pub(crate) fn rfind(&self, pattern: &str, to: usize) -> Option<usize> {
+        let mut to = to;
+        loop {
+            let start = self.string.get(..to)?.rfind(pattern)?;
+            let end = start + pattern.len();
+            if self.is_boundary(start) && self.is_boundary(end) {
+                return Some(start);
+            }
+            let (previous, _) = self.string[..end].char_indices().next_back()?;
+            to = previous;
+        }
+    }
+
+    /// byte offset of the char after the one at `offset`
+    pub(crate) fn next_char(&self, offset: usize) -> usize {
+        offset
+            + self.string[offset..]
+                .chars()
+                .next()
+                .map_or(1, char::len_utf8)
+    }
+
+    /// byte offset at which the search that follows a match from
+    /// `start` to `end` resumes, which skips a cluster past an empty
+    /// match
+    pub(crate) fn resume(&self, start: usize, end: usize) -> usize {
+        if start == end {
+            self.next_boundary(start)
+        } else {
+            end
+        }
+    }
+}
+
+/// Iterator over the matches of a pattern in a
+/// [Utf8Stream](crate::Utf8Stream) along with their byte offsets, see
+/// [`Utf8Stream::match_indices`](crate::Utf8Stream::match_indices).
+#[derive(Clone, Debug)]
+pub struct MatchIndices<'a> {
+    haystack: Haystack<'a>,
+    pattern: &'a str,
+    from: usize,
+}
+impl<'a> MatchIndices<'a> {
+    pub(crate) fn new(haystack: Haystack<'a>, pattern: &'a str) -> MatchIndices<'a> {
+        MatchIndices {
+            haystack,
+            pattern,
+            from: 0,
+        }
+    }
+}
+impl<'a> Iterator for MatchIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        let length = self.haystack.string.len();
+        let start = self.haystack.find(self.pattern, self.from, length)?;
+        let end = start + self.pattern.len();
+        self.from = self.haystack.resume(start, end);
+        Some((start, &self.haystack.string[start..end]))
+    }
+}
+impl FusedIterator for MatchIndices<'_> {}
+
+/// Iterator over the byte offsets of the matches of a pattern in a
+/// [Utf8Stream](crate::Utf8Stream), see
+/// [`Utf8Stream::find_iter`](crate::Utf8Stream::find_iter).
+#[derive(Clone, Debug)]
+pub struct FindIter<'a> {
+    inner: MatchIndices<'a>,
+}
+impl<'a> FindIter<'a> {
+    pub(crate) fn new(haystack: Haystack<'a>, pattern: &'a str) -> FindIter<'a> {
+        FindIter {
+            inner: MatchIndices::new(haystack, pattern),
+        }
+    }
+}
+impl Iterator for FindIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        self.inner.next().map(|(start, _)| start)
+    }
+}
+impl FusedIterator for FindIter<'_> {}
+
+/// Iterator over the substrings of a [Utf8Stream](crate::Utf8Stream)
+/// separated by a pattern, see
+/// [`Utf8Stream::split`](crate::Utf8Stream::split),
+/// [`Utf8Stream::splitn`](crate::Utf8Stream::splitn) and
+/// [`Utf8Stream::split_terminator`](crate::Utf8Stream::split_terminator).
+#[derive(Clone, Debug)]
+pub struct Split<'a> {
+    haystack: Haystack<'a>,
+    pattern: &'a str,
+    start: usize,
+    from: usize,
+    limit: Option<usize>,
+    terminator: bool,
+    finished: bool,
+}
+impl<'a> Split<'a> {
+    pub(crate) fn new(
+        haystack: Haystack<'a>,
+        pattern: &'a str,
+        limit: Option<usize>,
+        terminator: bool,
+    ) -> Split<'a> {
+        Split {
+            haystack,
+            pattern,
+            start: 0,
+            from: 0,
+            limit,
+            terminator,
+            finished: false,
+        }
+    }
+
+    /// Returns the part of the stream not yet split
+    pub fn remainder(&self) -> Option<&'a str> {
+        (!self.finished).then(|| &self.haystack.string[self.start..])
+    }
+}
+impl<'a> Iterator for Split<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.finished {
+            return None;
+        }
+        let string = self.haystack.string;
+        match &mut self.limit {
+            Some(0) => {
+                self.finished = true;
+                return None;
+            }
+            Some(1) => {
+                self.finished = true;
+                return Some(&string[self.start..]);
+            }
+            Some(limit) => *limit -= 1,
+            None => {}
+        }
+        match self.haystack.find(self.pattern, self.from, string.len()) {
+            Some(start) => {
+                let piece = &string[self.start..start];
+                self.start = start + self.pattern.len();
+                self.from = self.haystack.resume(start, self.start);
+                Some(piece)
+            }
+            None => {
+                self.finished = true;
+                let piece = &string[self.start..];
+                (!self.terminator || !piece.is_empty()).then_some(piece)
+            }
+        }
+    }
+}
+impl FusedIterator for Split<'_> {}
+
+/// the cuts made to the end of the data of a
+/// [Utf8Stream](crate::Utf8Stream) by `pop`, `truncate` and `clear`,
+/// from which an [Anchor] tells whether data it searched has changed.
+///
+/// Offsets count from the first byte pushed into the stream, as
+/// [`Position::offset`](crate::Position::offset) does. Being a record
+/// of how the data came about it never affects comparisons.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Cuts {
+    count: u64,
+    /// the number of cuts made up to and including a cut along with
+    /// the lowest offset cut from then on, in increasing order of
+    /// both
+    lowest: Vec<(u64, usize)>,
+}
+
+impl PartialEq for Cuts {
+    fn eq(&self, _: &Cuts) -> bool {
+        true
+    }
+}
+impl Eq for Cuts {}
+impl PartialOrd for Cuts {
+    fn partial_cmp(&self, other: &Cuts) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Cuts {
+    fn cmp(&self, _: &Cuts) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Cuts {
+    /// records that the data was cut at `offset`
+    pub(crate) fn cut(&mut self, offset: usize) {
+        self.count += 1;
+        while self
+            .lowest
+            .last()
+            .is_some_and(|&(_, lowest)| lowest >= offset)
+        {
+            self.lowest.pop();
+        }
+        self.lowest.push((self.count, offset));
+    }
+
+    /// forgets the cuts made before `base`, as they all leave nothing
+    /// to search before it
+    pub(crate) fn discarded(&mut self, base: usize) {
+        let below = self.lowest.partition_point(|&(_, lowest)| lowest < base);
+        self.lowest.drain(..below.saturating_sub(1));
+    }
+
+    /// the lowest offset cut after the first `seen` cuts
+    fn since(&self, seen: u64) -> Option<usize> {
+        let next = self.lowest.partition_point(|&(count, _)| count <= seen);
+        self.lowest.get(next).map(|&(_, lowest)| lowest)
+    }
+}
+
+/// the offset at which an incremental search of a
+/// [Utf8Stream](crate::Utf8Stream) resumes, counted from the first
+/// byte pushed into the stream rather than from the start of its
+/// buffer such that it stays put when consumed data gets discarded
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Anchor {
+    offset: usize,
+    cuts: u64,
+    /// the end of each match reported in the buffer along with the
+    /// offset the search resumed at after it, as a cut takes back the
+    /// matches that end past it but not the others
+    reported: Vec<(usize, usize)>,
+}
+
+impl Anchor {
+    pub(crate) fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// catches up with `stream`: moves the offset back to `reach`
+    /// bytes before the lowest offset cut from its end since the
+    /// previous call, which it returns, when the search went past
+    /// there, though not before the last match the cut leaves, and
+    /// forward to the start of its buffer when the data before it got
+    /// discarded
+    pub(crate) fn update(&mut self, stream: &Utf8Stream, reach: usize) -> Option<usize> {
+        let cut = stream
+            .cuts
+            .since(self.cuts)
+            .filter(|&cut| self.offset > cut.saturating_sub(reach));
+        self.cuts = stream.cuts.count;
+        if let Some(cut) = cut {
+            let kept = self.reported.partition_point(|&(end, _)| end <= cut);
+            self.reported.truncate(kept);
+            let resume = self.reported.last().map_or(0, |&(_, resume)| resume);
+            self.offset = cut.saturating_sub(reach).max(resume);
+        }
+        let base = stream.location.base.offset;
+        let behind = self.reported.partition_point(|&(_, resume)| resume <= base);
+        self.reported.drain(..behind);
+        self.offset = self.offset.max(base);
+        // an empty match at the end of the data resumes past it
+        debug_assert!(self.offset - base <= stream.len() + 1);
+        cut
+    }
+
+    /// the offset in the buffer of `stream`
+    pub(crate) fn get(&self, stream: &Utf8Stream) -> usize {
+        self.offset - stream.location.base.offset
+    }
+
+    /// moves the offset to `offset` in the buffer of `stream`
+    pub(crate) fn set(&mut self, stream: &Utf8Stream, offset: usize) {
+        self.offset = stream.location.base.offset + offset;
+    }
+
+    /// records a match that ends at `end` in the buffer of `stream`
+    /// and moves the offset to `resume`
+    pub(crate) fn matched(&mut self, stream: &Utf8Stream, end: usize, resume: usize) {
+        let base = stream.location.base.offset;
+        self.reported.push((base + end, base + resume));
+        self.offset = base + resume;
+    }
+}
+
+/// Finder searches a [Utf8Stream](crate::Utf8Stream) for a pattern
+/// incrementally, resuming each search where the previous one stopped
+/// such that data already searched is not scanned again as more data
+/// is pushed into the stream.
+///
+/// Since data pushed later may extend the last cluster of the stream,
+/// [`find`](Self::find) only reports matches that end before it,
+/// while [`finish`](Self::finish) reports the matches up to the end of
+/// the data once no more data is to come.
+///
+/// Matches are reported at their byte offsets in
+/// [`Utf8Stream::as_str`](crate::Utf8Stream::as_str) at the time of the
+/// call, while the finder keeps its place as consumed data gets
+/// discarded, as [bounded](crate::Utf8Stream::with_capacity_bounded)
+/// streams and [`records`](crate::Utf8Stream::records) do, and
+/// searches again the data that may now match once data gets cut from
+/// the end of the stream with [`pop`](crate::Utf8Stream::pop),
+/// [`truncate`](crate::Utf8Stream::truncate) or
+/// [`clear`](crate::Utf8Stream::clear). Data discarded before it has
+/// been searched is never searched.
+///
+/// ## Example
+///
+/// ```
+/// use utf8_stream::{Finder, Utf8Stream};
+///
+/// let mut stream = Utf8Stream::new("red ❤");
+/// let mut finder = Finder::new("❤");
+/// assert_eq!(finder.find(&stream), None);
+///
+/// stream.push("\u{fe0f} ❤ heart");
+/// assert_eq!(finder.find(&stream), Some(11));
+/// assert_eq!(finder.find(&stream), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Finder {
+    pattern: String,
+    anchor: Anchor,
+}
+
+impl Finder {
+    /// Creates a new [Finder](Self) for `pattern` that starts
+    /// searching at the beginning of the stream
+    pub fn new(pattern: &str) -> Finder {
+        Finder {
+            pattern: pattern.to_string(),
+            anchor: Anchor::default(),
+        }
+    }
+
+    /// Returns the pattern of the finder
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the byte offset at which the next search starts,
+    /// counted from the first byte pushed into the stream like
+    /// [`Position::offset`](crate::Position::offset)
+    pub fn offset(&self) -> usize {
+        self.anchor.offset()
+    }
+
+    /// Returns the byte offset of the next match of the pattern that
+    /// ends before the last cluster of `stream`, which data pushed
+    /// later may still extend
+    pub fn find(&mut self, stream: &Utf8Stream) -> Option<usize> {
+        self.find_before(stream, stream.clusters.last)
+    }
+
+    /// Returns the byte offset of the next match of the pattern in
+    /// `stream`, up to the end of its data
+    pub fn finish(&mut self, stream: &Utf8Stream) -> Option<usize> {
+        self.find_before(stream, stream.len())
+    }
+
+    fn find_before(&mut self, stream: &Utf8Stream, to: usize) -> Option<usize> {
+        self.anchor
+            .update(stream, self.pattern.len().saturating_sub(1));
+        let haystack = stream.haystack();
+        let from = self.anchor.get(stream);
+        match haystack.find(&self.pattern, from, to) {
+            Some(start) => {
+                let end = start + self.pattern.len();
+                self.anchor
+                    .matched(stream, end, haystack.resume(start, end));
+                Some(start)
+            }
+            None => {
+                // matches that start before this offset would have to
+                // end before `to` and were ruled out already
+                let string = stream.as_str();
+                let mut offset = (to + 1).saturating_sub(self.pattern.len().max(1));
+                while !string.is_char_boundary(offset) {
+                    offset += 1;
+                }
+                self.anchor.set(stream, from.max(offset));
+                None
+            }
+        }
+    }
+}
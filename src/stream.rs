@@ -1,13 +1,27 @@
-use std::alloc::Layout;
+use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
+use std::io::{Read, SeekFrom};
 use std::iter::{
     DoubleEndedIterator, ExactSizeIterator, Extend, FromIterator, IntoIterator, Iterator,
 };
-use std::marker::PhantomData;
+use std::num::NonZeroUsize;
 use std::ops::Deref;
 
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::case;
+use crate::framing::Records;
 use crate::heuristics::get_utf8_at_index;
-use crate::internal::{grow_ptr, shrink_ptr, to_slice_ptr_from_display};
+use crate::index::ClusterIndex;
+use crate::internal::split_valid_utf8;
+use crate::position::Locator;
+use crate::reader::Utf8Reader;
+use crate::search::{Cuts, FindIter, Haystack, MatchIndices, Split};
+use crate::units::{Bytes, Chars, Graphemes, Lines, Sentences, Words, Wrap};
+use crate::{
+    CaseLocale, ColumnUnit, InvalidPolicy, NormalizationForm, Normalizer, Position, Utf8StreamError,
+};
 
 /// Utf8Stream
 ///
@@ -21,120 +35,1262 @@ use crate::internal::{grow_ptr, shrink_ptr, to_slice_ptr_from_display};
 #[doc(alias = "Stream")]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Utf8Stream<'g> {
-    pub(crate) ptr: *mut u8,
+    pub(crate) buffer: Cow<'g, str>,
     pub(crate) index: usize,
-    pub(crate) length: usize,
-    _marker: PhantomData<&'g u8>,
+    pub(crate) tail: Option<usize>,
+    pub(crate) pending: Vec<u8>,
+    pub(crate) policy: InvalidPolicy,
+    pub(crate) bound: Option<usize>,
+    pub(crate) clusters: ClusterIndex,
+    pub(crate) location: Locator,
+    pub(crate) normalizer: Option<Normalizer>,
+    pub(crate) case_locale: CaseLocale,
+    /// whether the rest of a record that exceeded the limit of
+    /// [`records`](Self::records) is being discarded
+    pub(crate) discarding_record: bool,
+    pub(crate) cuts: Cuts,
 }
 
 impl<'g> Default for Utf8Stream<'g> {
     fn default() -> Utf8Stream<'g> {
         Utf8Stream {
-            ptr: to_slice_ptr_from_display(""),
+            buffer: Cow::Borrowed(""),
             index: 0,
-            length: 0,
-            _marker: PhantomData,
+            tail: None,
+            pending: Vec::new(),
+            policy: InvalidPolicy::default(),
+            bound: None,
+            clusters: ClusterIndex::default(),
+            location: Locator::default(),
+            normalizer: None,
+            case_locale: CaseLocale::default(),
+            discarding_record: false,
+            cuts: Cuts::default(),
         }
     }
 }
-impl<'g> Drop for Utf8Stream<'g> {
-    fn drop(&mut self) {
-        if !self.ptr.is_null() {
-            let layout = Layout::array::<u8>(self.length).unwrap();
-            unsafe {
-                std::alloc::dealloc(self.ptr, layout);
+impl<'g> Utf8Stream<'g> {
+    /// Creates a new [Utf8Stream](Self) from any implementor of [`Display`]
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("red❤️heart");
+    /// assert_eq!(stream.as_str(), "red❤️heart");
+    /// ```
+    pub fn new<T: Display>(input: T) -> Utf8Stream<'g> {
+        let buffer = input.to_string();
+        Utf8Stream {
+            clusters: ClusterIndex::new(&buffer),
+            buffer: Cow::Owned(buffer),
+            index: 0,
+            tail: None,
+            pending: Vec::new(),
+            policy: InvalidPolicy::default(),
+            bound: None,
+            location: Locator::default(),
+            normalizer: None,
+            case_locale: CaseLocale::default(),
+            discarding_record: false,
+            cuts: Cuts::default(),
+        }
+    }
+
+    /// Creates a new [Utf8Stream](Self) that borrows `input` instead of
+    /// copying it. The stream only copies its data into an owned
+    /// buffer when more data gets pushed into it.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let input = String::from("red❤️");
+    /// let mut stream = Utf8Stream::borrowed(&input);
+    ///
+    /// assert_eq!(stream.is_borrowed(), true);
+    /// assert_eq!(stream.as_str().as_ptr(), input.as_ptr());
+    /// assert_eq!(stream.next().as_deref(), Some("r"));
+    ///
+    /// stream.push("heart");
+    /// assert_eq!(stream.is_borrowed(), false);
+    /// assert_eq!(stream.as_str(), "red❤️heart");
+    /// ```
+    pub fn borrowed(input: &'g str) -> Utf8Stream<'g> {
+        Utf8Stream {
+            buffer: Cow::Borrowed(input),
+            clusters: ClusterIndex::new(input),
+            ..Utf8Stream::default()
+        }
+    }
+
+    /// Creates a new [Utf8Stream](Self) that borrows `bytes` as per
+    /// [`borrowed`](Self::borrowed), returning an error when they are
+    /// not valid utf8.
+    ///
+    /// ```
+    /// use utf8_stream::{Utf8Stream, Utf8StreamError};
+    ///
+    /// let stream = Utf8Stream::borrowed_bytes(b"red heart").unwrap();
+    /// assert_eq!(stream.as_str(), "red heart");
+    /// assert_eq!(
+    ///     Utf8Stream::borrowed_bytes(b"red\xffheart"),
+    ///     Err(Utf8StreamError::InvalidSequence { offset: 3, length: 1 })
+    /// );
+    /// assert_eq!(
+    ///     Utf8Stream::borrowed_bytes(&[114, 101, 100, 226, 157]),
+    ///     Err(Utf8StreamError::IncompleteTail { offset: 3, bytes: vec![226, 157] })
+    /// );
+    /// ```
+    pub fn borrowed_bytes(bytes: &'g [u8]) -> Result<Utf8Stream<'g>, Utf8StreamError> {
+        match split_valid_utf8(bytes) {
+            (valid, None, []) => Ok(Utf8Stream::borrowed(valid)),
+            (valid, None, rest) => Err(Utf8StreamError::IncompleteTail {
+                offset: valid.len(),
+                bytes: rest.to_vec(),
+            }),
+            (valid, Some(length), _) => Err(Utf8StreamError::InvalidSequence {
+                offset: valid.len(),
+                length,
+            }),
+        }
+    }
+
+    /// Returns `true` while the stream borrows the data it was
+    /// created from, see [`borrowed`](Self::borrowed).
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.buffer, Cow::Borrowed(_))
+    }
+
+    /// Converts the stream into one that owns its data, copying it
+    /// if it is borrowed.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    ///
+    /// let stream = {
+    ///     let input = String::from("red❤️");
+    ///     Utf8Stream::borrowed(&input).into_owned()
+    /// };
+    /// assert_eq!(stream.as_str(), "red❤️");
+    /// ```
+    pub fn into_owned(self) -> Utf8Stream<'static> {
+        Utf8Stream {
+            buffer: Cow::Owned(self.buffer.into_owned()),
+            index: self.index,
+            tail: self.tail,
+            pending: self.pending,
+            policy: self.policy,
+            bound: self.bound,
+            clusters: self.clusters,
+            location: self.location,
+            normalizer: self.normalizer,
+            case_locale: self.case_locale,
+            discarding_record: self.discarding_record,
+            cuts: self.cuts,
+        }
+    }
+
+    /// Creates a [Utf8Reader](crate::Utf8Reader) that fills an
+    /// [Utf8Stream](Self) from any implementor of [`Read`] as it gets
+    /// iterated.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    ///
+    /// let file = std::fs::File::open("README.md").unwrap();
+    /// for cluster in Utf8Stream::from_reader(file) {
+    ///     print!("{}", cluster.unwrap());
+    /// }
+    /// ```
+    pub fn from_reader<R: Read>(reader: R) -> Utf8Reader<R> {
+        Utf8Reader::new(reader)
+    }
+
+    /// Creates a [Utf8AsyncReader](crate::Utf8AsyncReader) that fills
+    /// an [Utf8Stream](Self) from any implementor of tokio's
+    /// `AsyncRead` as it gets polled.
+    ///
+    /// Only available with the `tokio` cargo feature.
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    /// use tokio::io::AsyncWriteExt;
+    /// use utf8_stream::Utf8Stream;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let (client, mut server) = tokio::io::duplex(64);
+    /// let mut reader = Utf8Stream::from_async_reader(client);
+    /// server.write_all("❤️".as_bytes()).await.unwrap();
+    /// drop(server);
+    /// assert_eq!(reader.next().await.unwrap().unwrap(), "❤️");
+    /// # })
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub fn from_async_reader<R: tokio::io::AsyncRead + Unpin>(
+        reader: R,
+    ) -> crate::Utf8AsyncReader<R> {
+        crate::Utf8AsyncReader::new(reader)
+    }
+
+    /// Creates an empty [Utf8Stream](Self) that handles invalid
+    /// utf8 sequences as per the given [InvalidPolicy](crate::InvalidPolicy)
+    ///
+    /// ```
+    /// use utf8_stream::{InvalidPolicy, Utf8Stream, Utf8StreamError};
+    /// let mut stream = Utf8Stream::with_policy(InvalidPolicy::Strict);
+    ///
+    /// assert_eq!(
+    ///     stream.push_bytes(b"red\xffheart"),
+    ///     Err(Utf8StreamError::InvalidSequence { offset: 3, length: 1 })
+    /// );
+    /// assert_eq!(stream.as_str(), "red");
+    /// ```
+    pub fn with_policy(policy: InvalidPolicy) -> Utf8Stream<'g> {
+        Utf8Stream {
+            policy,
+            ..Utf8Stream::default()
+        }
+    }
+
+    /// Creates an empty [Utf8Stream](Self) that never holds more than
+    /// `capacity` bytes.
+    ///
+    /// The bytes of the clusters already iterated are reclaimed as
+    /// needed to make room for new data, and pushing data that still
+    /// does not fit returns a
+    /// [`CapacityExceeded`](crate::Utf8StreamError::CapacityExceeded)
+    /// error so that producers can wait for the stream to be
+    /// consumed.
+    ///
+    /// ```
+    /// use utf8_stream::{Utf8Stream, Utf8StreamError};
+    /// let mut stream = Utf8Stream::with_capacity_bounded(9);
+    ///
+    /// stream.try_push("red❤️").unwrap();
+    /// assert_eq!(
+    ///     stream.try_push("heart"),
+    ///     Err(Utf8StreamError::CapacityExceeded { capacity: 9, length: 14 })
+    /// );
+    /// assert_eq!(stream.next().as_deref(), Some("r"));
+    /// assert_eq!(stream.next().as_deref(), Some("e"));
+    /// assert_eq!(stream.next().as_deref(), Some("d"));
+    /// stream.try_push("he").unwrap();
+    /// assert_eq!(stream.as_str(), "❤️he");
+    /// ```
+    pub fn with_capacity_bounded(capacity: usize) -> Utf8Stream<'g> {
+        Utf8Stream {
+            buffer: Cow::Owned(String::with_capacity(capacity)),
+            bound: Some(capacity),
+            ..Utf8Stream::default()
+        }
+    }
+
+    /// Returns the maximum number of bytes held by a stream created
+    /// with [`with_capacity_bounded`](Self::with_capacity_bounded)
+    pub fn capacity_bound(&self) -> Option<usize> {
+        self.bound
+    }
+
+    /// Returns how many more bytes a bounded stream accepts, counting
+    /// the bytes of the clusters already iterated as free and the
+    /// text held back by the normalizer as taken, or `None` when the
+    /// stream is unbounded.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::with_capacity_bounded(8);
+    ///
+    /// stream.try_push("red").unwrap();
+    /// assert_eq!(stream.remaining_capacity(), Some(5));
+    /// stream.next();
+    /// assert_eq!(stream.remaining_capacity(), Some(6));
+    /// assert_eq!(Utf8Stream::new("red").remaining_capacity(), None);
+    /// ```
+    pub fn remaining_capacity(&self) -> Option<usize> {
+        let held = self.buffer.len() - self.index + self.pending.len() + self.normalizer_held();
+        self.bound.map(|capacity| capacity.saturating_sub(held))
+    }
+
+    /// Returns `true` when a bounded stream cannot accept any more
+    /// bytes until some of its clusters get iterated.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::with_capacity_bounded(3);
+    ///
+    /// stream.try_push("red").unwrap();
+    /// assert_eq!(stream.is_full(), true);
+    /// stream.next();
+    /// assert_eq!(stream.is_full(), false);
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.remaining_capacity() == Some(0)
+    }
+
+    /// Creates an empty [Utf8Stream](Self) that normalizes the data
+    /// pushed into it to the given
+    /// [NormalizationForm](crate::NormalizationForm).
+    ///
+    /// The trailing segment of each push that may still combine with
+    /// upcoming data is held back by a [Normalizer](crate::Normalizer)
+    /// until a push brings a starter it cannot combine with or until
+    /// [`finish`](Self::finish) gets called.
+    ///
+    /// ```
+    /// use utf8_stream::{NormalizationForm, Utf8Stream};
+    /// let mut stream = Utf8Stream::with_normalization(NormalizationForm::Nfc);
+    ///
+    /// stream.push("cafe");
+    /// assert_eq!(stream.as_str(), "caf");
+    /// stream.push_bytes("\u{301}".as_bytes()).unwrap();
+    /// stream.finish().unwrap();
+    /// assert_eq!(stream.as_str(), "caf\u{e9}");
+    /// ```
+    pub fn with_normalization(form: NormalizationForm) -> Utf8Stream<'g> {
+        Utf8Stream {
+            normalizer: Some(Normalizer::new(form)),
+            ..Utf8Stream::default()
+        }
+    }
+
+    /// Returns the [NormalizationForm](crate::NormalizationForm)
+    /// applied to the data pushed into the stream, if any
+    pub fn normalization(&self) -> Option<NormalizationForm> {
+        self.normalizer.as_ref().map(Normalizer::form)
+    }
+
+    /// Sets the [NormalizationForm](crate::NormalizationForm) applied
+    /// to the data pushed from now on, flushing the text held back by
+    /// the previous one.
+    pub fn set_normalization(&mut self, form: Option<NormalizationForm>) {
+        if let Some(mut normalizer) = self.normalizer.take() {
+            self.push_str(&normalizer.finish());
+        }
+        self.normalizer = form.map(Normalizer::new);
+    }
+
+    /// Returns a new [Utf8Stream](Self) holding the data of the stream
+    /// normalized to the given
+    /// [NormalizationForm](crate::NormalizationForm)
+    ///
+    /// ```
+    /// use utf8_stream::{NormalizationForm, Utf8Stream};
+    /// let stream = Utf8Stream::new("cafe\u{301}");
+    ///
+    /// assert_eq!(stream.cluster_count(), 4);
+    /// assert_eq!(stream.normalize(NormalizationForm::Nfc).as_str(), "caf\u{e9}");
+    /// assert_eq!(stream.normalize(NormalizationForm::Nfc).len(), 5);
+    /// ```
+    pub fn normalize(&self, form: NormalizationForm) -> Utf8Stream<'static> {
+        self.transformed(form.normalize(self.as_str()))
+    }
+
+    /// Returns the [CaseLocale](crate::CaseLocale) of the case mapping
+    /// transforms of the stream
+    pub fn case_locale(&self) -> CaseLocale {
+        self.case_locale
+    }
+
+    /// Sets the [CaseLocale](crate::CaseLocale) of the case mapping
+    /// transforms of the stream
+    ///
+    /// ```
+    /// use utf8_stream::{CaseLocale, Utf8Stream};
+    /// let mut stream = Utf8Stream::new("istanbul");
+    ///
+    /// stream.set_case_locale(CaseLocale::Turkic);
+    /// assert_eq!(stream.to_uppercase().as_str(), "İSTANBUL");
+    /// ```
+    pub fn set_case_locale(&mut self, locale: CaseLocale) {
+        self.case_locale = locale;
+    }
+
+    /// Returns a new [Utf8Stream](Self) holding the data of the stream
+    /// in lowercase, as per the special casing rules of its
+    /// [CaseLocale](crate::CaseLocale)
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("ΟΔΟΣ Straße");
+    ///
+    /// assert_eq!(stream.to_lowercase().as_str(), "οδος straße");
+    /// ```
+    pub fn to_lowercase(&self) -> Utf8Stream<'static> {
+        self.transformed(case::to_lowercase(self.as_str(), self.case_locale))
+    }
+
+    /// Returns a new [Utf8Stream](Self) holding the data of the stream
+    /// in uppercase, as per the special casing rules of its
+    /// [CaseLocale](crate::CaseLocale)
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("Straße");
+    ///
+    /// assert_eq!(stream.to_uppercase().as_str(), "STRASSE");
+    /// ```
+    pub fn to_uppercase(&self) -> Utf8Stream<'static> {
+        self.transformed(case::to_uppercase(self.as_str(), self.case_locale))
+    }
+
+    /// Returns a new [Utf8Stream](Self) holding the data of the stream
+    /// with the first grapheme cluster of each word in titlecase and
+    /// the rest in lowercase
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("ǆungla ÉTÉ e\u{301}te\u{301}");
+    ///
+    /// assert_eq!(stream.to_titlecase().as_str(), "ǅungla Été E\u{301}te\u{301}");
+    /// ```
+    pub fn to_titlecase(&self) -> Utf8Stream<'static> {
+        self.transformed(case::to_titlecase(self.as_str(), self.case_locale))
+    }
+
+    /// Returns a new [Utf8Stream](Self) holding the full case folding
+    /// of the data of the stream, as per the special casing rules of
+    /// its [CaseLocale](crate::CaseLocale), for caseless comparison
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("Straße ΣΊΣΥΦΟΣ");
+    ///
+    /// assert_eq!(stream.case_fold().as_str(), "strasse σίσυφοσ");
+    /// ```
+    pub fn case_fold(&self) -> Utf8Stream<'static> {
+        self.transformed(case::case_fold(self.as_str(), self.case_locale))
+    }
+
+    /// returns a stream holding `string` under the policies of `self`
+    fn transformed(&self, string: String) -> Utf8Stream<'static> {
+        let mut stream = Utf8Stream::from(string);
+        stream.policy = self.policy;
+        stream.case_locale = self.case_locale;
+        stream
+    }
+
+    /// Returns the [InvalidPolicy](crate::InvalidPolicy) of the stream
+    pub fn policy(&self) -> InvalidPolicy {
+        self.policy
+    }
+
+    /// Sets the [InvalidPolicy](crate::InvalidPolicy) applied to the
+    /// bytes pushed from now on
+    ///
+    /// ```
+    /// use utf8_stream::{InvalidPolicy, Utf8Stream};
+    /// let mut stream = Utf8Stream::new("red");
+    ///
+    /// stream.set_policy(InvalidPolicy::Skip);
+    /// stream.push_bytes(b"\xff\xfeheart").unwrap();
+    /// assert_eq!(stream.as_str(), "redheart");
+    /// ```
+    pub fn set_policy(&mut self, policy: InvalidPolicy) {
+        self.policy = policy;
+    }
+
+    /// Pushes more string-like data into an [Utf8Stream](Self)
+    ///
+    /// An incomplete trailing sequence held back by
+    /// [`push_bytes`](Self::push_bytes) can no longer be completed
+    /// and is handled as per [`finish`](Self::finish), except that
+    /// with [InvalidPolicy::Strict](crate::InvalidPolicy::Strict) it
+    /// is discarded. Input that does not fit into a
+    /// [bounded](Self::with_capacity_bounded) stream is discarded as
+    /// well, see [`try_push`](Self::try_push).
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::new("red❤️");
+    ///
+    /// stream.push("heart");
+    ///
+    /// assert_eq!(stream.as_str(), "red❤️heart");
+    /// ```
+    pub fn push<T: Display>(&mut self, input: T) {
+        if self.finish_pending().is_err() {
+            self.pending.clear();
+        }
+        self.push_str(&input.to_string());
+    }
+
+    /// Pushes raw bytes into an [Utf8Stream](Self).
+    ///
+    /// The bytes may end in the middle of a multi-byte sequence, in
+    /// which case the incomplete trailing sequence is held back until
+    /// the next call to `push_bytes` completes it. Invalid sequences
+    /// are handled as per the stream's
+    /// [InvalidPolicy](crate::InvalidPolicy), which only returns an
+    /// error when [strict](crate::InvalidPolicy::Strict).
+    ///
+    /// A [bounded](Self::with_capacity_bounded) stream rejects the
+    /// whole chunk with a
+    /// [`CapacityExceeded`](crate::Utf8StreamError::CapacityExceeded)
+    /// error when it, or the replacements and escapes of its invalid
+    /// sequences, exceed the [`remaining_capacity`](Self::remaining_capacity).
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::new("red");
+    ///
+    /// stream.push_bytes(&[226, 157]).unwrap();
+    /// assert_eq!(stream.as_str(), "red");
+    /// assert_eq!(stream.pending(), &[226, 157]);
+    ///
+    /// stream.push_bytes(&[164, 239, 184, 143]).unwrap();
+    /// stream.push_bytes(b"heart").unwrap();
+    /// assert_eq!(stream.as_str(), "red❤️heart");
+    /// ```
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), Utf8StreamError> {
+        if let (Some(capacity), Some(remaining)) = (self.bound, self.remaining_capacity())
+            && bytes.len() > remaining
+        {
+            return Err(Utf8StreamError::CapacityExceeded {
+                capacity,
+                length: capacity - remaining + bytes.len(),
+            });
+        }
+        let mut input = self.pending.clone();
+        input.extend_from_slice(bytes);
+        // stages the output of the policy such that a chunk whose
+        // replacements or escapes do not fit is rejected as a whole
+        let mut staged = String::with_capacity(input.len());
+        let mut rest = input.as_slice();
+        let (pending, invalid) = loop {
+            let (valid, invalid, remainder) = split_valid_utf8(rest);
+            staged.push_str(valid);
+            match invalid {
+                Some(length) if self.policy == InvalidPolicy::Strict => {
+                    break (&[][..], Some(length));
+                }
+                Some(length) => {
+                    let sequence = &rest[valid.len()..valid.len() + length];
+                    self.policy.substitute(sequence, &mut staged);
+                    rest = remainder;
+                }
+                None => break (remainder, None),
             }
+        };
+        self.push_held(&staged, pending.len())?;
+        self.pending = pending.to_vec();
+        match invalid {
+            Some(length) => Err(Utf8StreamError::InvalidSequence {
+                offset: self.buffer.len(),
+                length,
+            }),
+            None => Ok(()),
         }
     }
-}
-impl<'g> Utf8Stream<'g> {
-    /// Creates a new [Utf8Stream](Self) from any implementor of [`Display`]
+
+    /// Returns the bytes of an incomplete trailing sequence held back
+    /// by [`push_bytes`](Self::push_bytes).
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::default();
+    ///
+    /// stream.push_bytes(&[240, 159, 145]);
+    /// assert_eq!(stream.pending(), &[240, 159, 145]);
+    /// stream.push_bytes(&[169]);
+    /// assert_eq!(stream.pending(), &[]);
+    /// assert_eq!(stream.as_str(), "👩");
+    /// ```
+    pub fn pending(&self) -> &[u8] {
+        &self.pending
+    }
+
+    /// Signals that no more bytes will be pushed, handling an
+    /// incomplete trailing sequence held back by
+    /// [`push_bytes`](Self::push_bytes) as per the stream's
+    /// [InvalidPolicy](crate::InvalidPolicy). When the replacement
+    /// does not fit into a [bounded](Self::with_capacity_bounded)
+    /// stream, the sequence stays [`pending`](Self::pending) so that
+    /// `finish` can be retried once there is room.
+    ///
+    /// ```
+    /// use utf8_stream::{InvalidPolicy, Utf8Stream, Utf8StreamError};
+    /// let mut stream = Utf8Stream::new("red");
+    ///
+    /// stream.push_bytes(&[226, 157]).unwrap();
+    /// stream.finish().unwrap();
+    /// assert_eq!(stream.as_str(), "red\u{fffd}");
+    ///
+    /// stream.set_policy(InvalidPolicy::Strict);
+    /// stream.push_bytes(&[226, 157]).unwrap();
+    /// assert_eq!(
+    ///     stream.finish(),
+    ///     Err(Utf8StreamError::IncompleteTail { offset: 6, bytes: vec![226, 157] })
+    /// );
+    /// ```
+    pub fn finish(&mut self) -> Result<(), Utf8StreamError> {
+        let result = self.finish_pending();
+        if let Some(normalizer) = self.normalizer.as_mut() {
+            let rest = normalizer.finish();
+            self.append_str(&rest, 0)?;
+        }
+        result
+    }
+
+    /// handles an incomplete trailing sequence as per
+    /// [`finish`](Self::finish) without flushing the text held back
+    /// by the normalizer
+    pub(crate) fn finish_pending(&mut self) -> Result<(), Utf8StreamError> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        match self.policy {
+            InvalidPolicy::Strict => Err(Utf8StreamError::IncompleteTail {
+                offset: self.buffer.len(),
+                bytes: std::mem::take(&mut self.pending),
+            }),
+            policy => {
+                let mut substitute = String::new();
+                policy.substitute(&self.pending, &mut substitute);
+                self.try_push_str(&substitute)?;
+                self.pending.clear();
+                Ok(())
+            }
+        }
+    }
+
+    /// Fallible version of [`push`](Self::push) which returns an
+    /// error instead of aborting when the buffer cannot grow and
+    /// reports an incomplete trailing sequence that can no longer be
+    /// completed as per [`finish`](Self::finish).
+    ///
+    /// ```
+    /// use utf8_stream::{InvalidPolicy, Utf8Stream, Utf8StreamError};
+    /// let mut stream = Utf8Stream::with_policy(InvalidPolicy::Strict);
+    ///
+    /// stream.push_bytes(&[114, 101, 100, 226, 157]).unwrap();
+    /// assert_eq!(
+    ///     stream.try_push("heart"),
+    ///     Err(Utf8StreamError::IncompleteTail { offset: 3, bytes: vec![226, 157] })
+    /// );
+    /// assert_eq!(stream.try_push("heart"), Ok(()));
+    /// assert_eq!(stream.as_str(), "redheart");
+    /// ```
+    pub fn try_push<T: Display>(&mut self, input: T) -> Result<(), Utf8StreamError> {
+        self.finish_pending()?;
+        self.try_push_str(&input.to_string())
+    }
+
+    pub(crate) fn push_str(&mut self, input: &str) {
+        if self.bound.is_some() || self.normalizer.is_some() {
+            self.try_push_str(input).ok();
+        } else {
+            self.buffer.to_mut().push_str(input);
+            self.clusters.appended(&self.buffer, self.tail.is_some());
+        }
+    }
+
+    pub(crate) fn try_push_str(&mut self, input: &str) -> Result<(), Utf8StreamError> {
+        self.push_held(input, 0)
+    }
+
+    /// pushes `input` through the normalizer, provided that `held`
+    /// more bytes still fit into a bounded stream along with it
+    fn push_held(&mut self, input: &str, held: usize) -> Result<(), Utf8StreamError> {
+        match self.normalizer.as_ref() {
+            Some(normalizer) => {
+                // the text held back counts against the bound such that
+                // `finish` can always flush it, and the normalizer only
+                // takes the input once it fits
+                let mut normalizer = normalizer.clone();
+                let ready = normalizer.push(input);
+                self.append_str(&ready, held + normalizer.finished_len())?;
+                self.normalizer = Some(normalizer);
+                Ok(())
+            }
+            None => self.append_str(input, held),
+        }
+    }
+
+    /// length of the text the normalizer would flush on
+    /// [`finish`](Self::finish)
+    fn normalizer_held(&self) -> usize {
+        self.normalizer.as_ref().map_or(0, Normalizer::finished_len)
+    }
+
+    /// appends `input` to the buffer, provided that `held` more bytes
+    /// still fit into a bounded stream along with it
+    fn append_str(&mut self, input: &str, held: usize) -> Result<(), Utf8StreamError> {
+        if let Some(capacity) = self.bound {
+            let length = input.len() + held;
+            if self.buffer.len() + length > capacity {
+                self.discard_consumed();
+            }
+            if self.buffer.len() + length > capacity {
+                return Err(Utf8StreamError::CapacityExceeded {
+                    capacity,
+                    length: self.buffer.len() + length,
+                });
+            }
+        }
+        if input.is_empty() {
+            return Ok(());
+        }
+        let buffer = self.buffer.to_mut();
+        buffer
+            .try_reserve(input.len())
+            .map_err(|_| Utf8StreamError::AllocationFailure {
+                size: buffer.len().saturating_add(input.len()),
+            })?;
+        buffer.push_str(input);
+        self.clusters.appended(&self.buffer, self.tail.is_some());
+        Ok(())
+    }
+
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::new("red❤️heart");
+    ///
+    /// assert_eq!(stream.contains("❤️"), true);
+    /// assert_eq!(stream.contains("heart"), true);
+    /// ```
+    pub fn contains<T: Display>(&mut self, input: T) -> bool {
+        self.as_str().contains(&input.to_string())
+    }
+
+    /// Caseless version of [`contains`](Self::contains) which compares
+    /// the full case folding of the stream and of `input`
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("STRASSE❤️");
+    ///
+    /// assert_eq!(stream.contains_ignore_case("straße"), true);
+    /// assert_eq!(stream.contains_ignore_case("strait"), false);
+    /// ```
+    pub fn contains_ignore_case<T: Display>(&self, input: T) -> bool {
+        let locale = self.case_locale;
+        case::case_fold(self.as_str(), locale)
+            .contains(&case::case_fold(&input.to_string(), locale))
+    }
+
+    /// Returns the byte offset of the first match of `pattern` in the
+    /// stream that starts and ends on extended grapheme cluster
+    /// boundaries, regardless of its cursors.
+    ///
+    /// Unlike [`str::find`] it never matches part of a cluster, such
+    /// that searching for "❤" does not find the one within "❤️".
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("red❤️heart❤");
+    ///
+    /// assert_eq!(stream.find("heart"), Some(9));
+    /// assert_eq!(stream.find("❤"), Some(14));
+    /// assert_eq!(stream.find("blue"), None);
+    /// ```
+    pub fn find(&self, pattern: &str) -> Option<usize> {
+        self.haystack().find(pattern, 0, self.len())
+    }
+
+    /// Returns the byte offset of the last match of `pattern` in the
+    /// stream that starts and ends on extended grapheme cluster
+    /// boundaries, regardless of its cursors.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("❤red❤️heart");
+    ///
+    /// assert_eq!(stream.rfind("❤"), Some(0));
+    /// assert_eq!(stream.rfind("❤️"), Some(6));
+    /// ```
+    pub fn rfind(&self, pattern: &str) -> Option<usize> {
+        self.haystack().rfind(pattern, self.len())
+    }
+
+    /// Returns an iterator over the byte offsets of the
+    /// non-overlapping matches of `pattern` in the stream, as per
+    /// [`find`](Self::find)
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("❤️❤❤️❤");
+    ///
+    /// assert_eq!(stream.find_iter("❤").collect::<Vec<usize>>(), vec![6, 15]);
+    /// ```
+    pub fn find_iter<'a>(&'a self, pattern: &'a str) -> FindIter<'a> {
+        FindIter::new(self.haystack(), pattern)
+    }
+
+    /// Returns an iterator over the non-overlapping matches of
+    /// `pattern` in the stream along with their byte offsets, as per
+    /// [`find`](Self::find)
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("red❤️red");
+    ///
+    /// assert_eq!(
+    ///     stream.match_indices("red").collect::<Vec<_>>(),
+    ///     vec![(0, "red"), (9, "red")]
+    /// );
+    /// ```
+    pub fn match_indices<'a>(&'a self, pattern: &'a str) -> MatchIndices<'a> {
+        MatchIndices::new(self.haystack(), pattern)
+    }
+
+    /// Returns an iterator over the substrings of the stream separated
+    /// by the matches of `pattern`, as per [`find`](Self::find)
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("red❤️heart❤fire");
+    ///
+    /// assert_eq!(stream.split("❤").collect::<Vec<&str>>(), vec!["red❤️heart", "fire"]);
+    /// assert_eq!(stream.split("").count(), 16);
+    /// ```
+    pub fn split<'a>(&'a self, pattern: &'a str) -> Split<'a> {
+        Split::new(self.haystack(), pattern, None, false)
+    }
+
+    /// Returns an iterator over at most `n` substrings of the stream
+    /// separated by the matches of `pattern`, the last of which holds
+    /// the rest of the stream
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("red,❤️,heart");
+    ///
+    /// assert_eq!(stream.splitn(2, ",").collect::<Vec<&str>>(), vec!["red", "❤️,heart"]);
+    /// ```
+    pub fn splitn<'a>(&'a self, n: usize, pattern: &'a str) -> Split<'a> {
+        Split::new(self.haystack(), pattern, Some(n), false)
+    }
+
+    /// Equivalent to [`split`](Self::split) except that a trailing
+    /// empty substring is skipped
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("red\n❤️\n");
+    ///
+    /// assert_eq!(
+    ///     stream.split_terminator("\n").collect::<Vec<&str>>(),
+    ///     vec!["red", "❤️"]
+    /// );
+    /// ```
+    pub fn split_terminator<'a>(&'a self, pattern: &'a str) -> Split<'a> {
+        Split::new(self.haystack(), pattern, None, true)
+    }
+
+    /// Returns an iterator over the non-overlapping matches of `regex`
+    /// in the stream that start and end on extended grapheme cluster
+    /// boundaries, regardless of its cursors.
+    ///
+    /// Requires the `regex` feature.
+    ///
+    /// ```
+    /// use regex::Regex;
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("red❤️heart❤");
+    /// let regex = Regex::new("❤|[a-z]+").unwrap();
+    ///
+    /// let found = stream.regex_find_iter(&regex).map(|found| found.as_str());
+    /// assert_eq!(found.collect::<Vec<&str>>(), vec!["red", "heart", "❤"]);
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn regex_find_iter<'a>(&'a self, regex: &'a regex::Regex) -> crate::RegexMatches<'a> {
+        crate::RegexMatches::new(self.haystack(), regex)
+    }
+
+    /// Returns an iterator over the captures of the non-overlapping
+    /// matches of `regex` in the stream that start and end on extended
+    /// grapheme cluster boundaries, regardless of its cursors.
+    ///
+    /// Requires the `regex` feature.
+    ///
+    /// ```
+    /// use regex::Regex;
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("red=❤️ fire=🔥");
+    /// let regex = Regex::new(r"(\w+)=(\S+)").unwrap();
+    ///
+    /// let values = stream.captures_iter(&regex).map(|captures| captures[2].to_string());
+    /// assert_eq!(values.collect::<Vec<String>>(), vec!["❤️", "🔥"]);
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn captures_iter<'a>(&'a self, regex: &'a regex::Regex) -> crate::RegexCaptures<'a> {
+        crate::RegexCaptures::new(self.haystack(), regex)
+    }
+
+    /// Returns an iterator over the records of the stream that end
+    /// with `delimiter`, starting at its cursor, for line- or
+    /// NUL-delimited protocols.
+    ///
+    /// Records are yielded without their delimiter as soon as it
+    /// arrives, while a trailing record without one stays in the
+    /// stream until data pushed later completes it. The records
+    /// yielded are dropped from the stream once the iterator is
+    /// dropped. An empty delimiter never matches.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::new("red\0❤️\0he");
+    ///
+    /// let records: Result<Vec<String>, _> = stream.records("\0").collect();
+    /// assert_eq!(records.unwrap(), vec!["red", "❤️"]);
+    /// stream.push("art\0");
+    /// assert_eq!(stream.records("\0").next(), Some(Ok("heart".to_string())));
+    /// ```
+    pub fn records<'a>(&'a mut self, delimiter: &'a str) -> Records<'a, 'g> {
+        Records::new(self, delimiter)
+    }
+
+    pub(crate) fn haystack(&self) -> Haystack<'_> {
+        Haystack::new(&self.buffer, self.clusters.boundaries(&self.buffer))
+    }
+
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::new("red❤️heart");
+    ///
+    /// stream.clear();
+    /// assert_eq!(stream.len(), 0);
+    /// assert_eq!(stream.is_empty(), true);
+    /// ```
+    pub fn clear(&mut self) {
+        match &mut self.buffer {
+            Cow::Borrowed(buffer) => *buffer = "",
+            Cow::Owned(buffer) => buffer.clear(),
+        }
+        self.index = 0;
+        self.tail = None;
+        self.pending.clear();
+        self.clusters = ClusterIndex::default();
+        self.location.reset();
+        self.normalizer = self.normalizer.as_ref().map(|n| Normalizer::new(n.form()));
+        self.discarding_record = false;
+        self.cuts.cut(0);
+    }
+
+    /// ```
+    /// let mut stream = Utf8Stream::new("red❤️heart");
+    ///
+    /// assert_eq!(stream.get(0), Some("r"));
+    /// assert_eq!(stream.get(3), Some("❤️"));
+    /// assert_eq!(stream.next().as_deref(), Some("r"));
+    /// assert_eq!(stream.next().as_deref(), Some("e"));
+    /// assert_eq!(stream.next().as_deref(), Some("d"));
+    /// assert_eq!(stream.next().as_deref(), Some("❤️"));
+    /// stream.rewind();
+    /// assert_eq!(stream.get(0), Some("r"));
+    /// assert_eq!(stream.get(3), Some("❤️"));
+    /// assert_eq!(stream.next().as_deref(), Some("r"));
+    /// assert_eq!(stream.next().as_deref(), Some("e"));
+    /// assert_eq!(stream.next().as_deref(), Some("d"));
+    /// assert_eq!(stream.next().as_deref(), Some("❤️"));
+    /// ```
+    pub fn rewind(&mut self) {
+        self.index = 0;
+        self.tail = None;
+        self.clusters.front = 0;
+        self.clusters.back = 0;
+        self.location.relocate(&self.buffer, 0);
+    }
+
+    /// Returns the line, column and byte offset of the front cursor
+    /// within the input, which line breaks (`\n`, `\r\n`, `\r`,
+    /// `U+2028` and `U+2029`) and clusters move forward as the cursor
+    /// advances.
+    ///
+    /// The byte offset is counted from the start of the input and
+    /// thus keeps growing when a [bounded](Self::with_capacity_bounded)
+    /// stream reclaims consumed clusters.
+    ///
+    /// ```
+    /// use utf8_stream::{Position, Utf8Stream};
+    /// let mut stream = Utf8Stream::new("red\r\n❤️heart");
+    ///
+    /// assert_eq!(stream.location(), Position { line: 1, column: 1, offset: 0 });
+    /// stream.advance_by(6).unwrap();
+    /// assert_eq!(stream.location(), Position { line: 2, column: 3, offset: 12 });
+    /// ```
+    pub fn location(&self) -> Position {
+        self.location.position
+    }
+
+    /// Returns the [ColumnUnit](crate::ColumnUnit) of the columns
+    /// reported by [`location`](Self::location)
+    pub fn column_unit(&self) -> ColumnUnit {
+        self.location.unit
+    }
+
+    /// Sets the [ColumnUnit](crate::ColumnUnit) of the columns
+    /// reported by [`location`](Self::location)
+    ///
+    /// ```
+    /// use utf8_stream::{ColumnUnit, Utf8Stream};
+    /// let mut stream = Utf8Stream::new("fire👩🏽‍🚒fighter");
+    ///
+    /// stream.advance_by(5).unwrap();
+    /// assert_eq!(stream.location().column, 6);
+    /// stream.set_column_unit(ColumnUnit::Chars);
+    /// assert_eq!(stream.location().column, 9);
+    /// stream.set_column_unit(ColumnUnit::Cells);
+    /// assert_eq!(stream.location().column, 7);
+    /// ```
+    pub fn set_column_unit(&mut self, unit: ColumnUnit) {
+        self.location.unit = unit;
+        self.location.relocate(&self.buffer, self.index);
+    }
+
+    /// Returns the distance between tab stops, in cells, used when
+    /// the [ColumnUnit](crate::ColumnUnit) is
+    /// [`Cells`](crate::ColumnUnit::Cells). Defaults to 8.
+    pub fn tab_width(&self) -> usize {
+        self.location.tab_width
+    }
+
+    /// Sets the distance between tab stops, in cells, used when the
+    /// [ColumnUnit](crate::ColumnUnit) is
+    /// [`Cells`](crate::ColumnUnit::Cells)
+    ///
+    /// ```
+    /// use utf8_stream::{ColumnUnit, Utf8Stream};
+    /// let mut stream = Utf8Stream::new("a\tb\tc");
+    ///
+    /// stream.set_column_unit(ColumnUnit::Cells);
+    /// stream.set_tab_width(4);
+    /// stream.advance_by(4).unwrap();
+    /// assert_eq!(stream.location().column, 9);
+    /// ```
+    pub fn set_tab_width(&mut self, width: usize) {
+        self.location.tab_width = width;
+        self.location.relocate(&self.buffer, self.index);
+    }
+
+    /// Returns the number of extended grapheme clusters left to
+    /// iterate between the front and the back cursors, without
+    /// rescanning the stream.
+    ///
+    /// This is also the [`len`](ExactSizeIterator::len) of the
+    /// stream as an iterator, whereas [`len`](Self::len) is the
+    /// number of bytes of the stream.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::new("red❤️");
+    ///
+    /// assert_eq!(stream.remaining_clusters(), 4);
+    /// stream.next();
+    /// stream.next_back();
+    /// assert_eq!(stream.remaining_clusters(), 2);
+    /// stream.push("\u{200d}🔥");
+    /// assert_eq!(stream.remaining_clusters(), 2);
+    /// ```
+    pub fn remaining_clusters(&self) -> usize {
+        let (_, start, _, count) = get_utf8_at_index(self, self.index);
+        let continuation = count > 0 && start < self.index && self.index < self.end();
+        self.clusters.remaining() + usize::from(continuation)
+    }
+
+    /// Returns the byte offset of the front cursor moved by
+    /// [`next`](Iterator::next), [`seek`](Self::seek) and
+    /// [`advance_by`](Self::advance_by)
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::new("❤️heart");
+    ///
+    /// assert_eq!(stream.position(), 0);
+    /// stream.next();
+    /// assert_eq!(stream.position(), 6);
+    /// ```
+    pub fn position(&self) -> usize {
+        self.index
+    }
+
+    /// Moves the cursor to the given byte offset, snapping it to the
+    /// nearest extended grapheme cluster boundary (the start of the
+    /// cluster when equally distant), and returns the new
+    /// [`position`](Self::position). The cursor never moves past
+    /// clusters already taken with
+    /// [`next_back`](DoubleEndedIterator::next_back).
+    ///
+    /// ```
+    /// use std::io::SeekFrom;
+    /// use utf8_stream::{Utf8Stream, Utf8StreamError};
+    /// let mut stream = Utf8Stream::new("red❤️heart");
+    ///
+    /// assert_eq!(stream.seek(SeekFrom::Start(4)), Ok(3));
+    /// assert_eq!(stream.next().as_deref(), Some("❤️"));
+    /// assert_eq!(stream.seek(SeekFrom::Current(-2)), Ok(9));
+    /// assert_eq!(stream.seek(SeekFrom::End(-1)), Ok(13));
+    /// assert_eq!(stream.next().as_deref(), Some("t"));
+    /// assert_eq!(
+    ///     stream.seek(SeekFrom::End(1)),
+    ///     Err(Utf8StreamError::IndexOutOfRange { index: 15, length: 14 })
+    /// );
+    /// ```
+    pub fn seek(&mut self, position: SeekFrom) -> Result<usize, Utf8StreamError> {
+        let length = self.buffer.len();
+        let (base, offset) = match position {
+            SeekFrom::Start(offset) => (0, i128::from(offset)),
+            SeekFrom::End(offset) => (length, i128::from(offset)),
+            SeekFrom::Current(offset) => (self.index, i128::from(offset)),
+        };
+        let target = base as i128 + offset;
+        if target < 0 || target > length as i128 {
+            return Err(Utf8StreamError::IndexOutOfRange {
+                index: target.max(0) as usize,
+                length,
+            });
+        }
+        let target = (target as usize).min(self.end());
+        let (_, start, _, count) = get_utf8_at_index(self, target);
+        self.index = if count == 0 || target - start <= start + count - target {
+            start
+        } else {
+            start + count
+        };
+        self.clusters.front = self.clusters.count_before(&self.buffer, self.index);
+        self.location.relocate(&self.buffer, self.index);
+        Ok(self.index)
+    }
+
+    /// Returns the next extended grapheme cluster without moving the
+    /// cursor
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::new("❤️heart");
+    ///
+    /// assert_eq!(stream.peek(), Some("❤️"));
+    /// assert_eq!(stream.next().as_deref(), Some("❤️"));
+    /// assert_eq!(stream.peek(), Some("h"));
+    /// ```
+    pub fn peek(&self) -> Option<&str> {
+        self.peek_nth(0)
+    }
+
+    /// Returns the `n`th extended grapheme cluster after the cursor,
+    /// counting from zero, without moving the cursor
     ///
     /// ```
     /// use utf8_stream::Utf8Stream;
-    /// let stream = Utf8Stream::new("red❤️heart");
-    /// assert_eq!(stream.as_str(), "red❤️heart");
+    /// let mut stream = Utf8Stream::new("❤️heart");
+    ///
+    /// assert_eq!(stream.peek_nth(1), Some("h"));
+    /// assert_eq!(stream.peek_nth(5), Some("t"));
+    /// assert_eq!(stream.peek_nth(6), None);
     /// ```
-    pub fn new<T: Display>(input: T) -> Utf8Stream<'g> {
-        let input = input.to_string();
-        let ptr = to_slice_ptr_from_display(&input);
-        let length = input.len();
-        Utf8Stream {
-            index: 0,
-            ptr,
-            length,
-            _marker: PhantomData,
-        }
+    pub fn peek_nth(&self, n: usize) -> Option<&str> {
+        self.remainder().graphemes(true).nth(n)
     }
 
-    /// Pushes more string-like data into an [Utf8Stream](Self)
+    /// Moves the cursor past the next `n` extended grapheme clusters.
+    ///
+    /// Returns `Err(k)` with the number `k` of clusters that could not
+    /// be skipped when the stream ends first, in which case the
+    /// cursor is left at the end of the stream.
     ///
     /// ```
+    /// use std::num::NonZeroUsize;
     /// use utf8_stream::Utf8Stream;
-    /// let mut stream = Utf8Stream::new("red❤️");
-    ///
-    /// stream.push("heart");
+    /// let mut stream = Utf8Stream::new("red❤️heart");
     ///
-    /// assert_eq!(stream.as_str(), "red❤️heart");
+    /// assert_eq!(stream.advance_by(4), Ok(()));
+    /// assert_eq!(stream.peek(), Some("h"));
+    /// assert_eq!(stream.advance_by(7), Err(NonZeroUsize::new(2).unwrap()));
+    /// assert_eq!(stream.position(), 14);
     /// ```
-    pub fn push<T: Display>(&mut self, input: T) {
-        let new_chars = input.to_string().as_bytes().to_vec();
-        let new_chars_length = new_chars.len();
-        let old_length = self.length;
-        let new_length = old_length + new_chars_length;
-        grow_ptr(self.ptr, old_length, new_length);
-        self.length = new_length;
-        for (a, c) in (old_length..new_length).zip(new_chars.iter()) {
-            unsafe {
-                self.ptr.add(a).write(*c);
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        for step in 0..n {
+            if self.next_cluster().is_none() {
+                return Err(NonZeroUsize::new(n - step).unwrap());
             }
         }
+        Ok(())
     }
 
+    /// Advances the cursor, returning the next extended grapheme
+    /// cluster borrowed from the stream.
+    ///
+    /// This is the zero-copy counterpart of [`next`](Iterator::next)
+    /// which yields owned clusters.
+    ///
     /// ```
     /// use utf8_stream::Utf8Stream;
-    /// let mut stream = Utf8Stream::new("red❤️heart");
+    /// let mut stream = Utf8Stream::new("red❤️");
     ///
-    /// assert_eq!(stream.contains("❤️"), true);
-    /// assert_eq!(stream.contains("heart"), true);
+    /// assert_eq!(stream.next_cluster(), Some("r"));
+    /// assert_eq!(stream.next().as_deref(), Some("e"));
+    /// assert_eq!(stream.next_cluster(), Some("d"));
+    /// assert_eq!(stream.next_cluster(), Some("❤️"));
+    /// assert_eq!(stream.next_cluster(), None);
     /// ```
-    pub fn contains<T: Display>(&mut self, input: T) -> bool {
-        self.as_str().contains(&input.to_string())
+    pub fn next_cluster(&mut self) -> Option<&str> {
+        let end = self.end();
+        if self.index >= end {
+            return None;
+        }
+        let (_, index, _, count) = get_utf8_at_index(self, self.index);
+        if count == 0 {
+            return None;
+        }
+        // data pushed after the cursor may have extended the cluster
+        // it last moved past, in which case only the extension is
+        // yielded
+        let start = index.max(self.index);
+        if start == index {
+            self.clusters.front += 1;
+        }
+        self.index = (index + count).min(end);
+        let cluster = self.buffer.get(start..self.index)?;
+        self.location.advance(cluster);
+        Some(cluster)
     }
 
+    /// Moves the back cursor, which starts at the end of the stream,
+    /// backwards returning the extended grapheme cluster that
+    /// precedes it borrowed from the stream.
+    ///
+    /// The back cursor never crosses the front cursor moved by
+    /// [`next_cluster`](Self::next_cluster), such that each cluster
+    /// is yielded once from either end. Until the back cursor moves
+    /// it follows the end of the stream as data gets pushed.
+    ///
+    /// This is the zero-copy counterpart of
+    /// [`next_back`](DoubleEndedIterator::next_back) which yields
+    /// owned clusters.
+    ///
     /// ```
     /// use utf8_stream::Utf8Stream;
-    /// let mut stream = Utf8Stream::new("red❤️heart");
+    /// let mut stream = Utf8Stream::new("red❤️");
     ///
-    /// stream.clear();
-    /// assert_eq!(stream.len(), 0);
-    /// assert_eq!(stream.is_empty(), true);
+    /// assert_eq!(stream.next_cluster(), Some("r"));
+    /// assert_eq!(stream.next_back_cluster(), Some("❤️"));
+    /// assert_eq!(stream.next_back_cluster(), Some("d"));
+    /// assert_eq!(stream.next_cluster(), Some("e"));
+    /// assert_eq!(stream.next_back_cluster(), None);
+    /// assert_eq!(stream.next_cluster(), None);
     /// ```
-    pub fn clear(&mut self) {
-        shrink_ptr(self.ptr, self.length, 1);
-        self.length = 0;
-        self.index = 0;
+    pub fn next_back_cluster(&mut self) -> Option<&str> {
+        let end = self.end();
+        if end <= self.index {
+            return None;
+        }
+        let (_, index, _, count) = get_utf8_at_index(self, end - 1);
+        if count == 0 {
+            return None;
+        }
+        // the cluster is only counted from the back when the back
+        // cursor moves past its start, as the front cursor may have
+        // moved past it already
+        if index >= self.index {
+            self.clusters.back += 1;
+        }
+        let index = index.max(self.index);
+        self.tail = Some(index);
+        self.buffer.get(index..end)
     }
 
-    /// ```
-    /// let mut stream = Utf8Stream::new("red❤️heart");
-    ///
-    /// assert_eq!(stream.get(0), Some("r"));
-    /// assert_eq!(stream.get(3), Some("❤️"));
-    /// assert_eq!(stream.next(), Some("r"));
-    /// assert_eq!(stream.next(), Some("e"));
-    /// assert_eq!(stream.next(), Some("d"));
-    /// assert_eq!(stream.next(), Some("❤️"));
-    /// stream.rewind();
-    /// assert_eq!(stream.get(0), Some("r"));
-    /// assert_eq!(stream.get(3), Some("❤️"));
-    /// assert_eq!(stream.next(), Some("r"));
-    /// assert_eq!(stream.next(), Some("e"));
-    /// assert_eq!(stream.next(), Some("d"));
-    /// assert_eq!(stream.next(), Some("❤️"));
-    /// ```
-    pub fn rewind(&mut self) {
-        self.index = 0;
+    /// byte offset of the back cursor
+    pub(crate) fn end(&self) -> usize {
+        self.tail
+            .unwrap_or(self.buffer.len())
+            .min(self.buffer.len())
     }
 
     /// Returns the number of bytes in the given stream. To retrieve
@@ -156,7 +1312,7 @@ impl<'g> Utf8Stream<'g> {
     /// assert_eq!(stream.len(), 4);
     /// ```
     pub fn len(&self) -> usize {
-        self.length
+        self.buffer.len()
     }
 
     /// ```
@@ -165,18 +1321,33 @@ impl<'g> Utf8Stream<'g> {
     /// assert_eq!(stream.as_str(), "👩🏽‍🚒");
     /// ```
     pub fn as_str(&self) -> &str {
-        let mut offset = self.length;
-        loop {
-            if let Ok(slice) =
-                std::str::from_utf8(unsafe { std::slice::from_raw_parts(self.ptr, offset) })
-            {
-                break slice;
-            }
-            if offset > 0 {
-                offset -= 1;
-            } else {
-                break "";
-            }
+        &self.buffer
+    }
+
+    /// Fallible version of [`as_str`](Self::as_str) which returns an
+    /// error when an incomplete trailing sequence held back by
+    /// [`push_bytes`](Self::push_bytes) is not part of the returned
+    /// string slice.
+    ///
+    /// ```
+    /// use utf8_stream::{Utf8Stream, Utf8StreamError};
+    /// let mut stream = Utf8Stream::new("red");
+    ///
+    /// assert_eq!(stream.try_as_str(), Ok("red"));
+    /// stream.push_bytes(&[226, 157]).unwrap();
+    /// assert_eq!(
+    ///     stream.try_as_str(),
+    ///     Err(Utf8StreamError::IncompleteTail { offset: 3, bytes: vec![226, 157] })
+    /// );
+    /// ```
+    pub fn try_as_str(&self) -> Result<&str, Utf8StreamError> {
+        if self.pending.is_empty() {
+            Ok(self.as_str())
+        } else {
+            Err(Utf8StreamError::IncompleteTail {
+                offset: self.buffer.len(),
+                bytes: self.pending.clone(),
+            })
         }
     }
     /// ```
@@ -184,8 +1355,227 @@ impl<'g> Utf8Stream<'g> {
     /// let stream = Utf8Stream::new("👩🏽‍🚒");
     /// assert_eq!(stream.as_bytes(), "👩🏽‍🚒");
     /// ```
-    pub fn as_bytes(&self) -> &'g [u8] {
-        unsafe { std::slice::from_raw_parts(self.ptr, self.length) }
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buffer.as_bytes()
+    }
+
+    /// Returns an iterator over the bytes of the stream starting at
+    /// its cursor.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::new("red❤️");
+    ///
+    /// stream.next();
+    /// assert_eq!(stream.bytes().collect::<Vec<u8>>(), vec![101, 100, 226, 157, 164, 239, 184, 143]);
+    /// ```
+    pub fn bytes(&self) -> Bytes<'_> {
+        Bytes::new(self.remainder())
+    }
+
+    /// Returns an iterator over the chars of the stream starting at
+    /// its cursor.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::new("red❤️");
+    ///
+    /// stream.next();
+    /// assert_eq!(stream.chars().collect::<String>(), "ed❤️");
+    /// assert_eq!(stream.chars().count(), 4);
+    /// ```
+    pub fn chars(&self) -> Chars<'_> {
+        Chars::new(self.remainder())
+    }
+
+    /// Returns an iterator over the extended grapheme clusters of the
+    /// stream starting at its cursor, without moving the cursor.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::new("fire👩🏽‍🚒fighter");
+    ///
+    /// stream.next();
+    /// assert_eq!(stream.graphemes().nth(3), Some("👩🏽‍🚒"));
+    /// assert_eq!(stream.next().as_deref(), Some("i"));
+    /// ```
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        let remainder = self.remainder();
+        let (_, start, _, _) = get_utf8_at_index(self, self.index);
+        // the rest of a cluster extended under the cursor is segmented
+        // on its own, so its clusters are not the ones in the index
+        let remaining = if start < self.index {
+            remainder.graphemes(true).count()
+        } else {
+            self.clusters.remaining()
+        };
+        Graphemes::new(remainder, remaining)
+    }
+
+    /// Returns an iterator over the words of the stream starting at
+    /// its cursor, as per the word boundaries of [Unicode Standard
+    /// Annex #29](https://www.unicode.org/reports/tr29/). Punctuation,
+    /// whitespace and emoji are not yielded.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("The quick (\"brown\") fox can't jump 32.3 feet, right?");
+    ///
+    /// assert_eq!(
+    ///     stream.words().collect::<Vec<&str>>(),
+    ///     vec!["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"]
+    /// );
+    /// ```
+    pub fn words(&self) -> Words<'_> {
+        Words::new(self.remainder())
+    }
+
+    /// Returns an iterator over the sentences of the stream starting
+    /// at its cursor, as per the sentence boundaries of [Unicode
+    /// Standard Annex #29](https://www.unicode.org/reports/tr29/).
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("Mr. Fox jumped. The dog was too lazy.");
+    ///
+    /// assert_eq!(
+    ///     stream.sentences().collect::<Vec<&str>>(),
+    ///     vec!["Mr. ", "Fox jumped. ", "The dog was too lazy."]
+    /// );
+    /// ```
+    pub fn sentences(&self) -> Sentences<'_> {
+        Sentences::new(self.remainder())
+    }
+
+    /// Returns an iterator over the lines of the stream starting at
+    /// its cursor.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("red\n❤️\r\nheart");
+    ///
+    /// assert_eq!(stream.lines().collect::<Vec<&str>>(), vec!["red", "❤️", "heart"]);
+    /// ```
+    pub fn lines(&self) -> Lines<'_> {
+        Lines::new(self.remainder())
+    }
+
+    /// Returns the number of terminal cells the stream occupies
+    /// starting at its cursor, where wide characters and emoji
+    /// presentation sequences take two cells as per [Unicode Standard
+    /// Annex #11](https://www.unicode.org/reports/tr11/) and
+    /// [Unicode Technical Standard #51](https://www.unicode.org/reports/tr51/).
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("fire👩🏽‍🚒fighter");
+    ///
+    /// assert_eq!(stream.display_width(), 13);
+    /// assert_eq!(stream.len(), 26);
+    /// ```
+    pub fn display_width(&self) -> usize {
+        self.graphemes().map(UnicodeWidthStr::width).sum()
+    }
+
+    /// Returns the stream starting at its cursor cut down to whole
+    /// extended grapheme clusters that fit `width` terminal cells,
+    /// including the `ellipsis` that gets appended when the stream
+    /// does not fit. The ellipsis is returned on its own when it does
+    /// not fit either.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("fire👩🏽‍🚒fighter");
+    ///
+    /// assert_eq!(stream.truncate_to_width(13, "…"), "fire👩🏽‍🚒fighter");
+    /// assert_eq!(stream.truncate_to_width(7, "…"), "fire👩🏽‍🚒…");
+    /// assert_eq!(stream.truncate_to_width(6, "…"), "fire…");
+    /// assert_eq!(stream.truncate_to_width(6, ""), "fire👩🏽‍🚒");
+    /// ```
+    pub fn truncate_to_width(&self, width: usize, ellipsis: &str) -> Cow<'_, str> {
+        let remainder = self.remainder();
+        if self.display_width() <= width {
+            return Cow::Borrowed(remainder);
+        }
+        let available = width.saturating_sub(ellipsis.width());
+        let mut used = 0;
+        let mut end = 0;
+        for cluster in self.graphemes() {
+            used += cluster.width();
+            if used > available {
+                break;
+            }
+            end += cluster.len();
+        }
+        Cow::Owned(format!("{}{ellipsis}", &remainder[..end]))
+    }
+
+    /// Returns an iterator over the lines of the stream starting at
+    /// its cursor, wrapped between extended grapheme clusters to fit
+    /// `columns` terminal cells.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("fire👩🏽‍🚒fighter\n漢字");
+    ///
+    /// assert_eq!(
+    ///     stream.wrap(5).collect::<Vec<&str>>(),
+    ///     vec!["fire", "👩🏽‍🚒fig", "hter", "漢字"]
+    /// );
+    /// ```
+    pub fn wrap(&self, columns: usize) -> Wrap<'_> {
+        Wrap::new(self.remainder(), columns)
+    }
+
+    /// yields the cluster at the cursor only when it is known to be
+    /// complete: the last cluster in the buffer might still be
+    /// extended by upcoming data (e.g. by a combining mark), so it is
+    /// only complete once another cluster follows it or no more data
+    /// is expected.
+    pub(crate) fn next_complete(&mut self, eof: bool) -> Option<String> {
+        let mut graphemes = self.remainder().graphemes(true);
+        let cluster = graphemes.next()?;
+        if eof || graphemes.next().is_some() {
+            let cluster = cluster.to_string();
+            self.index += cluster.len();
+            self.clusters.front += 1;
+            self.location.advance(&cluster);
+            Some(cluster)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn discard_consumed(&mut self) {
+        // data pushed after the cursor moved past a cluster may have
+        // extended it, in which case the extension left in the buffer
+        // starts clusters of its own that the index does not count
+        let (_, start, _, _) = get_utf8_at_index(self, self.index);
+        let split = start < self.index;
+        match &mut self.buffer {
+            Cow::Borrowed(buffer) => *buffer = &buffer[self.index..],
+            Cow::Owned(buffer) => {
+                buffer.drain(..self.index);
+            }
+        }
+        self.tail = self.tail.map(|tail| tail.saturating_sub(self.index));
+        if split {
+            let end = self.end();
+            self.clusters = ClusterIndex::new(&self.buffer);
+            self.clusters.back =
+                self.clusters.count - self.clusters.count_before(&self.buffer, end);
+        } else {
+            self.clusters.discarded(self.index);
+        }
+        self.location.rebase();
+        self.cuts.discarded(self.location.base.offset);
+        self.index = 0;
+    }
+
+    pub(crate) fn remainder(&self) -> &str {
+        self.as_str()
+            .get(self.index..self.end())
+            .unwrap_or_default()
     }
 
     /// ```
@@ -195,7 +1585,7 @@ impl<'g> Utf8Stream<'g> {
     /// assert_eq!(stream.is_empty(), true);
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.length == 0
+        self.buffer.is_empty()
     }
     /// ```
     /// use utf8_stream::Utf8Stream;
@@ -205,7 +1595,7 @@ impl<'g> Utf8Stream<'g> {
     /// assert_eq!(stream.get(3), Some("❤️"));
     /// assert_eq!(stream.get(13), Some("t"));
     /// ```
-    pub fn get(&self, index: usize) -> Option<&'g str> {
+    pub fn get(&self, index: usize) -> Option<&str> {
         let (slice, _, _, count) = get_utf8_at_index(self, index);
         if count == 0 || count == 1 && &slice[0..1] == "\0" {
             None
@@ -213,6 +1603,115 @@ impl<'g> Utf8Stream<'g> {
             Some(slice)
         }
     }
+
+    /// Returns the number of extended grapheme clusters of the stream
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("fire👩🏽‍🚒fighter");
+    ///
+    /// assert_eq!(stream.cluster_count(), 12);
+    /// ```
+    pub fn cluster_count(&self) -> usize {
+        self.clusters.count
+    }
+
+    /// Returns the `n`th extended grapheme cluster of the stream,
+    /// counting from zero regardless of its cursors.
+    ///
+    /// The byte offset of every cluster gets indexed on the first
+    /// call to `nth_cluster`, [`cluster_to_byte`](Self::cluster_to_byte)
+    /// or [`byte_to_cluster`](Self::byte_to_cluster), after which
+    /// lookups take constant time and the index is kept in sync as
+    /// the stream changes.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("fire👩🏽‍🚒fighter");
+    ///
+    /// assert_eq!(stream.nth_cluster(4), Some("👩🏽‍🚒"));
+    /// assert_eq!(stream.nth_cluster(11), Some("r"));
+    /// assert_eq!(stream.nth_cluster(12), None);
+    /// ```
+    pub fn nth_cluster(&self, n: usize) -> Option<&str> {
+        let start = self.cluster_to_byte(n)?;
+        let end = self.cluster_to_byte(n + 1)?;
+        self.buffer
+            .get(start..end)
+            .filter(|cluster| !cluster.is_empty())
+    }
+
+    /// Returns the byte offset of the `n`th extended grapheme cluster
+    /// of the stream, or its length when `n` is the
+    /// [`cluster_count`](Self::cluster_count).
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("red❤️heart");
+    ///
+    /// assert_eq!(stream.cluster_to_byte(3), Some(3));
+    /// assert_eq!(stream.cluster_to_byte(4), Some(9));
+    /// assert_eq!(stream.cluster_to_byte(9), Some(14));
+    /// assert_eq!(stream.cluster_to_byte(10), None);
+    /// ```
+    pub fn cluster_to_byte(&self, n: usize) -> Option<usize> {
+        let boundaries = self.clusters.boundaries(&self.buffer);
+        match boundaries.get(n) {
+            Some(offset) => Some(*offset),
+            None if n == boundaries.len() => Some(self.buffer.len()),
+            None => None,
+        }
+    }
+
+    /// Returns the number of the extended grapheme cluster that
+    /// contains the byte at `offset`
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let stream = Utf8Stream::new("red❤️heart");
+    ///
+    /// assert_eq!(stream.byte_to_cluster(3), Some(3));
+    /// assert_eq!(stream.byte_to_cluster(8), Some(3));
+    /// assert_eq!(stream.byte_to_cluster(9), Some(4));
+    /// assert_eq!(stream.byte_to_cluster(14), None);
+    /// ```
+    pub fn byte_to_cluster(&self, offset: usize) -> Option<usize> {
+        if offset >= self.buffer.len() {
+            return None;
+        }
+        let boundaries = self.clusters.boundaries(&self.buffer);
+        Some(boundaries.partition_point(|&start| start <= offset) - 1)
+    }
+
+    /// Fallible version of [`get`](Self::get) which returns the
+    /// cluster containing the char that starts at byte `index`
+    ///
+    /// ```
+    /// use utf8_stream::{Utf8Stream, Utf8StreamError};
+    ///
+    /// let stream = Utf8Stream::new("red❤️heart");
+    ///
+    /// assert_eq!(stream.try_get(3), Ok("❤️"));
+    /// assert_eq!(stream.try_get(6), Ok("❤️"));
+    /// assert_eq!(stream.try_get(4), Err(Utf8StreamError::NotCharBoundary { index: 4 }));
+    /// assert_eq!(
+    ///     stream.try_get(14),
+    ///     Err(Utf8StreamError::IndexOutOfRange { index: 14, length: 14 })
+    /// );
+    /// ```
+    pub fn try_get(&self, index: usize) -> Result<&str, Utf8StreamError> {
+        if index >= self.buffer.len() {
+            return Err(Utf8StreamError::IndexOutOfRange {
+                index,
+                length: self.buffer.len(),
+            });
+        }
+        if !self.as_str().is_char_boundary(index) {
+            return Err(Utf8StreamError::NotCharBoundary { index });
+        }
+        let (slice, _, _, _) = get_utf8_at_index(self, index);
+        Ok(slice)
+    }
     /// ```
     /// use utf8_stream::Utf8Stream;
     ///
@@ -220,11 +1719,11 @@ impl<'g> Utf8Stream<'g> {
     ///
     /// assert_eq!(stream.last_printable(), Some("t"));
     /// ```
-    pub fn last_printable(&self) -> Option<&'g str> {
-        if self.length == 0 {
+    pub fn last_printable(&self) -> Option<&str> {
+        if self.buffer.is_empty() {
             None
         } else {
-            let mut index = self.length;
+            let mut index = self.buffer.len();
             loop {
                 if let Some(slice) = self.get(index) {
                     if slice != "\0" {
@@ -242,73 +1741,144 @@ impl<'g> Utf8Stream<'g> {
     ///
     /// let mut stream = Utf8Stream::new("red❤️heart");
     /// assert_eq!(stream.as_str(), "red❤️heart");
-    /// assert_eq!(stream.pop(), Some("t"));
-    /// assert_eq!(stream.pop(), Some("r"));
-    /// assert_eq!(stream.pop(), Some("a"));
-    /// assert_eq!(stream.pop(), Some("e"));
-    /// assert_eq!(stream.pop(), Some("h"));
-    /// assert_eq!(stream.pop(), Some("❤️"));
-    /// assert_eq!(stream.pop(), Some("d"));
-    /// assert_eq!(stream.pop(), Some("e"));
-    /// assert_eq!(stream.pop(), Some("r"));
-    /// ```
-    pub fn pop(&mut self) -> Option<&'g str> {
-        let old_length = self.length;
-        if self.length == 0 {
+    /// assert_eq!(stream.pop().as_deref(), Some("t"));
+    /// assert_eq!(stream.pop().as_deref(), Some("r"));
+    /// assert_eq!(stream.pop().as_deref(), Some("a"));
+    /// assert_eq!(stream.pop().as_deref(), Some("e"));
+    /// assert_eq!(stream.pop().as_deref(), Some("h"));
+    /// assert_eq!(stream.pop().as_deref(), Some("❤️"));
+    /// assert_eq!(stream.pop().as_deref(), Some("d"));
+    /// assert_eq!(stream.pop().as_deref(), Some("e"));
+    /// assert_eq!(stream.pop().as_deref(), Some("r"));
+    /// ```
+    pub fn pop(&mut self) -> Option<String> {
+        if self.buffer.is_empty() {
             return None;
         }
-        let (slice, index, offset, count) = get_utf8_at_index(self, self.length - 1);
-        if count > 0 {
-            self.length -= count;
-            Some(slice)
-        } else {
-            None
+        let (_, index, _, _) = get_utf8_at_index(self, self.buffer.len() - 1);
+        let cluster = match &mut self.buffer {
+            Cow::Borrowed(buffer) => {
+                let cluster = buffer[index..].to_string();
+                *buffer = &buffer[..index];
+                cluster
+            }
+            Cow::Owned(buffer) => buffer.split_off(index),
+        };
+        self.cuts.cut(self.location.base.offset + index);
+        if self.index > index {
+            self.clusters.front = self.clusters.front.saturating_sub(1);
+            self.location.relocate(&self.buffer, index);
+        }
+        if self.tail.is_some_and(|tail| tail <= index) {
+            self.clusters.back = self.clusters.back.saturating_sub(1);
+        }
+        self.index = self.index.min(index);
+        self.tail = self.tail.map(|tail| tail.min(index));
+        self.clusters.popped(&self.buffer);
+        Some(cluster)
+    }
+
+    /// Shortens the stream to `length` bytes, moving its cursors back
+    /// as needed. Has no effect when `length` is greater than the
+    /// [`len`](Self::len) of the stream.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `length` does not lie on a char boundary.
+    ///
+    /// ```
+    /// use utf8_stream::Utf8Stream;
+    /// let mut stream = Utf8Stream::new("red❤️heart");
+    ///
+    /// stream.truncate(9);
+    /// assert_eq!(stream.as_str(), "red❤️");
+    /// assert_eq!(stream.cluster_count(), 4);
+    /// stream.truncate(6);
+    /// assert_eq!(stream.as_str(), "red❤");
+    /// assert_eq!(stream.nth_cluster(3), Some("❤"));
+    /// ```
+    pub fn truncate(&mut self, length: usize) {
+        if length >= self.buffer.len() {
+            return;
+        }
+        assert!(
+            self.buffer.is_char_boundary(length),
+            "byte index {length} is not a char boundary"
+        );
+        let (_, start, _, _) = get_utf8_at_index(self, length);
+        let removed = self.buffer[start..].graphemes(true).count();
+        match &mut self.buffer {
+            Cow::Borrowed(buffer) => *buffer = &buffer[..length],
+            Cow::Owned(buffer) => buffer.truncate(length),
+        }
+        self.cuts.cut(self.location.base.offset + length);
+        self.clusters.truncated(&self.buffer, start, removed);
+        if self.index > length {
+            self.index = length;
+            self.clusters.front = self.clusters.count;
+            self.location.relocate(&self.buffer, length);
+        }
+        if self.tail.is_some_and(|tail| tail > length) {
+            self.tail = None;
+            self.clusters.back = 0;
+        }
+    }
+
+    /// Fallible version of [`pop`](Self::pop) which reports an empty
+    /// stream or an incomplete trailing sequence held back by
+    /// [`push_bytes`](Self::push_bytes), which would otherwise be
+    /// left behind the popped cluster.
+    ///
+    /// ```
+    /// use utf8_stream::{Utf8Stream, Utf8StreamError};
+    ///
+    /// let mut stream = Utf8Stream::new("d");
+    /// stream.push_bytes(&[226, 157]).unwrap();
+    /// assert_eq!(
+    ///     stream.try_pop(),
+    ///     Err(Utf8StreamError::IncompleteTail { offset: 1, bytes: vec![226, 157] })
+    /// );
+    /// stream.finish().unwrap();
+    /// assert_eq!(stream.try_pop().as_deref(), Ok("\u{fffd}"));
+    /// assert_eq!(stream.try_pop().as_deref(), Ok("d"));
+    /// assert_eq!(
+    ///     stream.try_pop(),
+    ///     Err(Utf8StreamError::IndexOutOfRange { index: 0, length: 0 })
+    /// );
+    /// ```
+    pub fn try_pop(&mut self) -> Result<String, Utf8StreamError> {
+        if !self.pending.is_empty() {
+            return Err(Utf8StreamError::IncompleteTail {
+                offset: self.buffer.len(),
+                bytes: self.pending.clone(),
+            });
         }
+        self.pop().ok_or(Utf8StreamError::IndexOutOfRange {
+            index: 0,
+            length: 0,
+        })
     }
 }
 impl<'g> Iterator for Utf8Stream<'g> {
-    type Item = &'g str;
+    type Item = String;
 
-    fn next(&mut self) -> Option<&'g str> {
-        if self.index == self.length {
-            None
-        } else {
-            let (slice, index, offset, count) = get_utf8_at_index(self, self.index);
-            if count > 0 {
-                if (self.index + count) <= self.length {
-                    self.index += count;
-                } else {
-                    self.index = self.length - 1;
-                }
-                Some(slice)
-            } else {
-                None
-            }
-        }
+    fn next(&mut self) -> Option<String> {
+        self.next_cluster().map(String::from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining_clusters();
+        (remaining, Some(remaining))
     }
 }
 impl<'g> DoubleEndedIterator for Utf8Stream<'g> {
-    fn next_back(&mut self) -> Option<&'g str> {
-        if self.index == 0 {
-            None
-        } else {
-            let (slice, index, offset, count) = get_utf8_at_index(self, self.index - 1);
-            if count > 0 {
-                if self.index >= count && (self.index - count) > 0 {
-                    self.index -= count;
-                } else {
-                    self.index = 0;
-                }
-                Some(slice)
-            } else {
-                None
-            }
-        }
+    fn next_back(&mut self) -> Option<String> {
+        self.next_back_cluster().map(String::from)
     }
 }
 impl<'g> ExactSizeIterator for Utf8Stream<'g> {
     fn len(&self) -> usize {
-        self.length
+        self.remaining_clusters()
     }
 }
 impl<'g> Extend<char> for Utf8Stream<'g> {
@@ -325,8 +1895,8 @@ impl<'g> Extend<String> for Utf8Stream<'g> {
         }
     }
 }
-impl<'g> Extend<&'g str> for Utf8Stream<'g> {
-    fn extend<T: IntoIterator<Item = &'g str>>(&mut self, iter: T) {
+impl<'a, 'g> Extend<&'a str> for Utf8Stream<'g> {
+    fn extend<T: IntoIterator<Item = &'a str>>(&mut self, iter: T) {
         for string in iter {
             self.push(string);
         }
@@ -334,9 +1904,7 @@ impl<'g> Extend<&'g str> for Utf8Stream<'g> {
 }
 impl<'g> Extend<u8> for Utf8Stream<'g> {
     fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
-        for u in iter {
-            self.push(char::from(u));
-        }
+        self.push_bytes(&iter.into_iter().collect::<Vec<u8>>()).ok();
     }
 }
 
@@ -363,23 +1931,27 @@ impl<'g> FromIterator<char> for Utf8Stream<'g> {
     }
 }
 
-impl<'g> FromIterator<&'g str> for Utf8Stream<'g> {
-    fn from_iter<I: IntoIterator<Item = &'g str>>(iter: I) -> Utf8Stream<'g> {
+impl<'a, 'g> FromIterator<&'a str> for Utf8Stream<'g> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Utf8Stream<'g> {
         let mut buf = Utf8Stream::default();
         buf.extend(iter);
         buf
     }
 }
 
-impl<'g> From<&str> for Utf8Stream<'g> {
-    fn from(s: &str) -> Utf8Stream<'g> {
-        Utf8Stream::new(s)
+impl<'g> From<&'g str> for Utf8Stream<'g> {
+    fn from(s: &'g str) -> Utf8Stream<'g> {
+        Utf8Stream::borrowed(s)
     }
 }
 
 impl<'g> From<String> for Utf8Stream<'g> {
     fn from(s: String) -> Utf8Stream<'g> {
-        Utf8Stream::new(s)
+        Utf8Stream {
+            clusters: ClusterIndex::new(&s),
+            buffer: Cow::Owned(s),
+            ..Utf8Stream::default()
+        }
     }
 }
 
@@ -396,7 +1968,7 @@ impl<'g> Display for Utf8Stream<'g> {
 }
 impl<'g> Debug for Utf8Stream<'g> {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        let length = self.length;
+        let length = self.buffer.len();
         let index = self.index;
         fn pad(byte: u8) -> String {
             let byte = byte.to_string();
@@ -416,6 +1988,58 @@ impl<'g> Debug for Utf8Stream<'g> {
     }
 }
 
+impl<'g> std::io::Write for Utf8Stream<'g> {
+    /// Writes raw bytes as per [`push_bytes`](Utf8Stream::push_bytes).
+    ///
+    /// A [bounded](Utf8Stream::with_capacity_bounded) stream only
+    /// accepts as many bytes as its
+    /// [`remaining_capacity`](Utf8Stream::remaining_capacity) and
+    /// fails with [`WouldBlock`](std::io::ErrorKind::WouldBlock) when
+    /// it is full, in which case none of `buf` gets written.
+    ///
+    /// With [InvalidPolicy::Strict](crate::InvalidPolicy::Strict),
+    /// the valid bytes in front of an invalid sequence are written
+    /// first and the sequence is reported by the next call, such that
+    /// an error always means that none of `buf` got written.
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut count = match self.remaining_capacity() {
+            Some(0) if !buf.is_empty() => buf.len(),
+            Some(remaining) => remaining.min(buf.len()),
+            None => buf.len(),
+        };
+        if self.policy == InvalidPolicy::Strict {
+            let mut input = self.pending.clone();
+            input.extend_from_slice(&buf[..count]);
+            if let (valid, Some(_), _) = split_valid_utf8(&input)
+                && valid.len() > self.pending.len()
+            {
+                count = valid.len() - self.pending.len();
+            }
+        }
+        loop {
+            match self.push_bytes(&buf[..count]) {
+                Ok(()) => return Ok(count),
+                // replacements and escapes may take more room than the
+                // bytes they stand for, so fewer bytes get written
+                Err(Utf8StreamError::CapacityExceeded { capacity, length }) if count > 1 => {
+                    count -= (length - capacity).clamp(1, count - 1);
+                }
+                Err(error) => return Err(error.into()),
+            }
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+impl<'g> std::fmt::Write for Utf8Stream<'g> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.finish_pending().map_err(|_| std::fmt::Error)?;
+        self.try_push_str(s).map_err(|_| std::fmt::Error)
+    }
+}
+
 impl<'g> Deref for Utf8Stream<'g> {
     type Target = str;
 
@@ -0,0 +1,256 @@
+use std::iter::{DoubleEndedIterator, FusedIterator, Iterator};
+
+use unicode_segmentation::{
+    Graphemes as UnicodeGraphemes, UnicodeSegmentation, UnicodeSentences, UnicodeWords,
+};
+use unicode_width::UnicodeWidthStr;
+
+/// Iterator over the bytes of a [Utf8Stream](crate::Utf8Stream)
+/// starting at its cursor, see [`Utf8Stream::bytes`](crate::Utf8Stream::bytes).
+#[derive(Clone, Debug)]
+pub struct Bytes<'a> {
+    inner: std::str::Bytes<'a>,
+}
+impl<'a> Bytes<'a> {
+    pub(crate) fn new(string: &'a str) -> Bytes<'a> {
+        Bytes {
+            inner: string.bytes(),
+        }
+    }
+}
+impl Iterator for Bytes<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl DoubleEndedIterator for Bytes<'_> {
+    fn next_back(&mut self) -> Option<u8> {
+        self.inner.next_back()
+    }
+}
+impl ExactSizeIterator for Bytes<'_> {}
+impl FusedIterator for Bytes<'_> {}
+
+/// Iterator over the chars of a [Utf8Stream](crate::Utf8Stream)
+/// starting at its cursor, see [`Utf8Stream::chars`](crate::Utf8Stream::chars).
+#[derive(Clone, Debug)]
+pub struct Chars<'a> {
+    inner: std::str::Chars<'a>,
+}
+impl<'a> Chars<'a> {
+    pub(crate) fn new(string: &'a str) -> Chars<'a> {
+        Chars {
+            inner: string.chars(),
+        }
+    }
+
+    /// Returns the remaining string slice
+    pub fn as_str(&self) -> &'a str {
+        self.inner.as_str()
+    }
+}
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl DoubleEndedIterator for Chars<'_> {
+    fn next_back(&mut self) -> Option<char> {
+        self.inner.next_back()
+    }
+}
+impl FusedIterator for Chars<'_> {}
+
+/// Iterator over the extended grapheme clusters of a
+/// [Utf8Stream](crate::Utf8Stream) starting at its cursor, see
+/// [`Utf8Stream::graphemes`](crate::Utf8Stream::graphemes).
+#[derive(Clone, Debug)]
+pub struct Graphemes<'a> {
+    inner: UnicodeGraphemes<'a>,
+    remaining: usize,
+}
+impl<'a> Graphemes<'a> {
+    pub(crate) fn new(string: &'a str, remaining: usize) -> Graphemes<'a> {
+        Graphemes {
+            inner: string.graphemes(true),
+            remaining,
+        }
+    }
+
+    /// Returns the remaining string slice
+    pub fn as_str(&self) -> &'a str {
+        self.inner.as_str()
+    }
+}
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let cluster = self.inner.next()?;
+        self.remaining = self.remaining.saturating_sub(1);
+        Some(cluster)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<'a> DoubleEndedIterator for Graphemes<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        let cluster = self.inner.next_back()?;
+        self.remaining = self.remaining.saturating_sub(1);
+        Some(cluster)
+    }
+}
+impl ExactSizeIterator for Graphemes<'_> {}
+impl FusedIterator for Graphemes<'_> {}
+
+/// Iterator over the words of a [Utf8Stream](crate::Utf8Stream)
+/// starting at its cursor, see [`Utf8Stream::words`](crate::Utf8Stream::words).
+#[derive(Debug)]
+pub struct Words<'a> {
+    inner: UnicodeWords<'a>,
+}
+impl<'a> Words<'a> {
+    pub(crate) fn new(string: &'a str) -> Words<'a> {
+        Words {
+            inner: string.unicode_words(),
+        }
+    }
+}
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<'a> DoubleEndedIterator for Words<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        self.inner.next_back()
+    }
+}
+impl FusedIterator for Words<'_> {}
+
+/// Iterator over the sentences of a [Utf8Stream](crate::Utf8Stream)
+/// starting at its cursor, see
+/// [`Utf8Stream::sentences`](crate::Utf8Stream::sentences).
+#[derive(Clone)]
+pub struct Sentences<'a> {
+    inner: UnicodeSentences<'a>,
+}
+impl<'a> Sentences<'a> {
+    pub(crate) fn new(string: &'a str) -> Sentences<'a> {
+        Sentences {
+            inner: string.unicode_sentences(),
+        }
+    }
+}
+impl<'a> Iterator for Sentences<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl FusedIterator for Sentences<'_> {}
+
+/// Iterator over the lines of a [Utf8Stream](crate::Utf8Stream)
+/// starting at its cursor, see [`Utf8Stream::lines`](crate::Utf8Stream::lines).
+///
+/// Lines are split at `\n` or `\r\n` and the line terminators are not
+/// included in the yielded slices.
+#[derive(Clone, Debug)]
+pub struct Lines<'a> {
+    inner: std::str::Lines<'a>,
+}
+impl<'a> Lines<'a> {
+    pub(crate) fn new(string: &'a str) -> Lines<'a> {
+        Lines {
+            inner: string.lines(),
+        }
+    }
+}
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<'a> DoubleEndedIterator for Lines<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        self.inner.next_back()
+    }
+}
+impl FusedIterator for Lines<'_> {}
+
+/// Iterator over the lines of a [Utf8Stream](crate::Utf8Stream)
+/// starting at its cursor wrapped to fit a number of terminal
+/// columns, see [`Utf8Stream::wrap`](crate::Utf8Stream::wrap).
+///
+/// Lines are wrapped between extended grapheme clusters, such that a
+/// cluster wider than the columns is yielded on a line of its own,
+/// and the line terminators are not included in the yielded slices.
+#[derive(Clone, Debug)]
+pub struct Wrap<'a> {
+    remainder: &'a str,
+    columns: usize,
+}
+impl<'a> Wrap<'a> {
+    pub(crate) fn new(string: &'a str, columns: usize) -> Wrap<'a> {
+        Wrap {
+            remainder: string,
+            columns,
+        }
+    }
+}
+impl<'a> Iterator for Wrap<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.remainder.is_empty() {
+            return None;
+        }
+        let mut width = 0;
+        for (offset, cluster) in self.remainder.grapheme_indices(true) {
+            if matches!(cluster, "\n" | "\r\n" | "\r") {
+                let line = &self.remainder[..offset];
+                self.remainder = &self.remainder[offset + cluster.len()..];
+                return Some(line);
+            }
+            width += cluster.width();
+            if width > self.columns && offset > 0 {
+                let line = &self.remainder[..offset];
+                self.remainder = &self.remainder[offset..];
+                return Some(line);
+            }
+        }
+        Some(std::mem::take(&mut self.remainder))
+    }
+}
+impl FusedIterator for Wrap<'_> {}
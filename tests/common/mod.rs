@@ -0,0 +1,42 @@
+//! strategies shared by the property tests
+#![allow(dead_code)]
+
+use proptest::prelude::*;
+
+/// ascii, multi-byte chars, combining marks, zero width joiners, skin
+/// tone modifiers, regional indicators and line breaks
+pub const CLUSTER_PIECES: &[&str] = &[
+    "a", "b", "c", " ", "❤", "\u{fe0f}", "\u{301}", "\u{200d}", "👩", "🏽", "🚒", "🇧", "🇷", "\r\n",
+];
+
+/// few pieces, such that patterns made of them match often, which
+/// still extend each other into clusters
+pub const SEARCH_PIECES: &[&str] = &["a", "b", "❤", "\u{fe0f}", "\u{301}", "\r", "\n", "🇧"];
+
+/// strings made of fewer than `length` of `pieces`
+pub fn text(pieces: &'static [&'static str], length: usize) -> impl Strategy<Value = String> {
+    proptest::collection::vec(proptest::sample::select(pieces), 0..length)
+        .prop_map(|pieces| pieces.concat())
+}
+
+/// an operation that changes the data or the cursors of a stream
+#[derive(Clone, Debug)]
+pub enum Operation {
+    Push(String),
+    PushBytes(Vec<u8>),
+    Pop,
+    Truncate(usize),
+    Next,
+    NextBack,
+}
+
+pub fn operation() -> impl Strategy<Value = Operation> {
+    prop_oneof![
+        "[a-z❤\u{fe0f}\u{301}\u{200d}👩🏽🚒🇧🇷]{0,4}".prop_map(Operation::Push),
+        proptest::collection::vec(any::<u8>(), 0..4).prop_map(Operation::PushBytes),
+        Just(Operation::Pop),
+        (0usize..32).prop_map(Operation::Truncate),
+        Just(Operation::Next),
+        Just(Operation::NextBack),
+    ]
+}
@@ -0,0 +1,8 @@
+use utf8_stream::Utf8Stream;
+
+fn main() {
+    let stream = Utf8Stream::new("red❤️");
+    let bytes = stream.as_bytes();
+    drop(stream);
+    println!("{bytes:?}");
+}
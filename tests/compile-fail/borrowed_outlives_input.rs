@@ -0,0 +1,9 @@
+use utf8_stream::Utf8Stream;
+
+fn main() {
+    let stream = {
+        let input = String::from("red❤️");
+        Utf8Stream::borrowed(&input)
+    };
+    println!("{stream}");
+}
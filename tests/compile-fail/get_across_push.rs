@@ -0,0 +1,8 @@
+use utf8_stream::Utf8Stream;
+
+fn main() {
+    let mut stream = Utf8Stream::new("red❤️");
+    let cluster = stream.get(3).unwrap();
+    stream.push("heart");
+    println!("{cluster}");
+}
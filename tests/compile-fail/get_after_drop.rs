@@ -0,0 +1,9 @@
+use utf8_stream::Utf8Stream;
+
+fn main() {
+    let cluster = {
+        let stream = Utf8Stream::new("red❤️");
+        stream.get(3).unwrap()
+    };
+    println!("{cluster}");
+}
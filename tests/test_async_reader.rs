@@ -0,0 +1,60 @@
+use futures::StreamExt;
+use tokio::io::AsyncWriteExt;
+
+use utf8_stream::{Utf8AsyncReader, Utf8Stream};
+
+#[tokio::test]
+async fn test_from_async_reader() {
+    let reader = Utf8Stream::from_async_reader("fire👩🏽‍🚒fighter".as_bytes());
+    assert_eq!(
+        reader.map(Result::unwrap).collect::<Vec<String>>().await,
+        vec!["f", "i", "r", "e", "👩🏽‍🚒", "f", "i", "g", "h", "t", "e", "r"]
+    );
+}
+
+#[tokio::test]
+async fn test_async_reader_over_duplex_with_split_sequences() {
+    let (client, mut server) = tokio::io::duplex(64);
+    let mut reader = Utf8AsyncReader::with_chunk_size(2, client);
+
+    let writer = tokio::spawn(async move {
+        for byte in "red❤️heart".bytes() {
+            server.write_all(&[byte]).await.unwrap();
+            server.flush().await.unwrap();
+            tokio::task::yield_now().await;
+        }
+    });
+
+    let mut clusters = Vec::<String>::new();
+    while let Some(cluster) = reader.next().await {
+        clusters.push(cluster.unwrap());
+    }
+    writer.await.unwrap();
+    assert_eq!(clusters, vec!["r", "e", "d", "❤️", "h", "e", "a", "r", "t"]);
+}
+
+#[tokio::test]
+async fn test_async_reader_holds_back_last_cluster() {
+    let (client, mut server) = tokio::io::duplex(64);
+    let mut reader = Utf8Stream::from_async_reader(client);
+
+    server.write_all(b"cafe").await.unwrap();
+    assert_eq!(reader.next().await.unwrap().unwrap(), "c");
+    assert_eq!(reader.next().await.unwrap().unwrap(), "a");
+    assert_eq!(reader.next().await.unwrap().unwrap(), "f");
+
+    server.write_all("\u{301}s".as_bytes()).await.unwrap();
+    drop(server);
+    assert_eq!(reader.next().await.unwrap().unwrap(), "e\u{301}");
+    assert_eq!(reader.next().await.unwrap().unwrap(), "s");
+    assert!(reader.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_async_reader_replaces_incomplete_tail_at_eof() {
+    let reader = Utf8Stream::from_async_reader(&[114, 101, 100, 226, 157][..]);
+    assert_eq!(
+        reader.map(Result::unwrap).collect::<Vec<String>>().await,
+        vec!["r", "e", "d", "\u{fffd}"]
+    );
+}
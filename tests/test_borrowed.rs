@@ -0,0 +1,85 @@
+use utf8_stream::{Utf8Stream, Utf8StreamError};
+
+#[test]
+fn test_borrowed_does_not_copy() {
+    let input = "fire👩🏽‍🚒fighter";
+    let stream = Utf8Stream::borrowed(input);
+    assert!(stream.is_borrowed());
+    assert_eq!(stream.as_str().as_ptr(), input.as_ptr());
+    assert_eq!(stream.as_bytes().as_ptr(), input.as_ptr());
+    assert_eq!(
+        stream.get(4).map(str::as_ptr),
+        input.get(4..).map(str::as_ptr)
+    );
+    assert_eq!(&*stream, input);
+}
+
+#[test]
+fn test_borrowed_iterates() {
+    let stream = Utf8Stream::from("red❤️heart");
+    assert!(stream.is_borrowed());
+    assert_eq!(
+        stream.collect::<Vec<String>>(),
+        vec!["r", "e", "d", "❤️", "h", "e", "a", "r", "t"]
+    );
+}
+
+#[test]
+fn test_borrowed_promotes_to_owned_on_push() {
+    let input = String::from("red");
+    let mut stream = Utf8Stream::borrowed(&input);
+    stream.push("❤️");
+    assert!(!stream.is_borrowed());
+    assert_eq!(stream.as_str(), "red❤️");
+    assert_eq!(input, "red");
+
+    let mut stream = Utf8Stream::borrowed(&input);
+    stream.push_bytes(&[226, 157]).unwrap();
+    assert!(stream.is_borrowed());
+    stream.push_bytes(&[164]).unwrap();
+    assert!(!stream.is_borrowed());
+    assert_eq!(stream.as_str(), "red❤");
+}
+
+#[test]
+fn test_borrowed_stays_borrowed_on_pop_and_clear() {
+    let mut stream = Utf8Stream::borrowed("red❤️");
+    assert_eq!(stream.pop().as_deref(), Some("❤️"));
+    assert!(stream.is_borrowed());
+    assert_eq!(stream.as_str(), "red");
+    stream.clear();
+    assert!(stream.is_borrowed());
+    assert!(stream.is_empty());
+}
+
+#[test]
+fn test_borrowed_bytes() {
+    let bytes = "red❤️".as_bytes();
+    let stream = Utf8Stream::borrowed_bytes(bytes).unwrap();
+    assert!(stream.is_borrowed());
+    assert_eq!(stream.as_bytes().as_ptr(), bytes.as_ptr());
+    assert_eq!(
+        Utf8Stream::borrowed_bytes(&bytes[..5]),
+        Err(Utf8StreamError::IncompleteTail {
+            offset: 3,
+            bytes: vec![226, 157]
+        })
+    );
+    assert_eq!(
+        Utf8Stream::borrowed_bytes(b"\xffred"),
+        Err(Utf8StreamError::InvalidSequence {
+            offset: 0,
+            length: 1
+        })
+    );
+}
+
+#[test]
+fn test_into_owned_outlives_input() {
+    let stream = {
+        let input = String::from("red❤️");
+        Utf8Stream::borrowed(&input).into_owned()
+    };
+    assert!(!stream.is_borrowed());
+    assert_eq!(stream.as_str(), "red❤️");
+}
@@ -0,0 +1,211 @@
+use std::io::{ErrorKind, Write as _};
+
+use proptest::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+use utf8_stream::{InvalidPolicy, Utf8Stream, Utf8StreamError};
+
+mod common;
+use common::{Operation, operation};
+
+#[test]
+fn test_bounded_stream_rejects_overflow() {
+    let mut stream = Utf8Stream::with_capacity_bounded(9);
+    assert_eq!(stream.capacity_bound(), Some(9));
+    stream.try_push("red❤️").unwrap();
+    assert!(stream.is_full());
+    assert_eq!(
+        stream.try_push("h"),
+        Err(Utf8StreamError::CapacityExceeded {
+            capacity: 9,
+            length: 10
+        })
+    );
+    assert_eq!(
+        stream.push_bytes(b"h"),
+        Err(Utf8StreamError::CapacityExceeded {
+            capacity: 9,
+            length: 10
+        })
+    );
+    assert_eq!(stream.as_str(), "red❤️");
+}
+
+#[test]
+fn test_bounded_stream_reclaims_consumed_clusters() {
+    let mut stream = Utf8Stream::with_capacity_bounded(16);
+    let mut clusters = Vec::new();
+    for _ in 0..64 {
+        stream.try_push("fire👩🏽‍🚒").unwrap_err();
+        clusters.extend(&mut stream);
+        stream.try_push("fire").unwrap();
+        clusters.extend(&mut stream);
+        stream.try_push("👩🏽‍🚒").unwrap();
+        clusters.extend(&mut stream);
+        assert!(stream.len() <= 16);
+    }
+    assert_eq!(clusters.len(), 64 * 5);
+    assert!(
+        clusters
+            .chunks(5)
+            .all(|chunk| chunk == ["f", "i", "r", "e", "👩🏽‍🚒"])
+    );
+}
+
+#[test]
+fn test_bounded_stream_yields_whole_clusters_across_reclaim() {
+    let mut stream = Utf8Stream::with_capacity_bounded(20);
+    stream.try_push("red👩🏽").unwrap();
+    assert_eq!(stream.next().as_deref(), Some("r"));
+    assert_eq!(stream.next().as_deref(), Some("e"));
+    assert_eq!(stream.next().as_deref(), Some("d"));
+    stream.push_bytes("\u{200d}🚒".as_bytes()).unwrap();
+    stream.push_bytes(&[240, 159]).unwrap();
+    assert_eq!(stream.remaining_capacity(), Some(3));
+    stream.push_bytes(&[148, 165]).unwrap();
+    assert_eq!(stream.next().as_deref(), Some("👩🏽‍🚒"));
+    assert_eq!(stream.next().as_deref(), Some("🔥"));
+    assert_eq!(stream.next(), None);
+}
+
+#[test]
+fn test_bounded_stream_io_write_backpressure() {
+    let mut stream = Utf8Stream::with_capacity_bounded(4);
+    assert_eq!(stream.write(b"red heart").unwrap(), 4);
+    assert_eq!(
+        stream.write(b"heart").unwrap_err().kind(),
+        ErrorKind::WouldBlock
+    );
+    assert_eq!(stream.next().as_deref(), Some("r"));
+    assert_eq!(stream.next().as_deref(), Some("e"));
+    assert_eq!(stream.write(b"heart").unwrap(), 2);
+    assert_eq!(stream.as_str(), "d he");
+}
+
+#[test]
+fn test_bounded_stream_rejects_expanded_chunk_whole() {
+    let mut stream = Utf8Stream::with_capacity_bounded(4);
+    assert_eq!(
+        stream.push_bytes(b"ab\xff"),
+        Err(Utf8StreamError::CapacityExceeded {
+            capacity: 4,
+            length: 5
+        })
+    );
+    assert_eq!(stream.as_str(), "");
+    stream.push_bytes(&[240, 159]).unwrap();
+    assert_eq!(
+        stream.push_bytes(b"\xff"),
+        Err(Utf8StreamError::CapacityExceeded {
+            capacity: 4,
+            length: 6
+        })
+    );
+    assert_eq!(stream.pending(), &[240, 159]);
+    stream.push_bytes(&[148, 165]).unwrap();
+    assert_eq!(stream.as_str(), "🔥");
+}
+
+#[test]
+fn test_bounded_stream_finish_keeps_tail_that_does_not_fit() {
+    let mut stream = Utf8Stream::with_capacity_bounded(4);
+    stream.push_bytes(b"abc\xe2").unwrap();
+    assert_eq!(
+        stream.finish(),
+        Err(Utf8StreamError::CapacityExceeded {
+            capacity: 4,
+            length: 6
+        })
+    );
+    assert_eq!(stream.pending(), b"\xe2");
+    assert_eq!(stream.as_str(), "abc");
+    for _ in 0..3 {
+        stream.next();
+    }
+    // the retry reclaims the consumed clusters to make room
+    assert_eq!(stream.finish(), Ok(()));
+    assert_eq!(stream.pending(), &[]);
+    assert_eq!(stream.as_str(), "\u{fffd}");
+}
+
+#[test]
+fn test_bounded_stream_io_write_escape_backpressure() {
+    let mut stream = Utf8Stream::with_capacity_bounded(4);
+    stream.set_policy(InvalidPolicy::Escape);
+    assert_eq!(stream.write(b"a\xff").unwrap(), 1);
+    assert_eq!(stream.as_str(), "a");
+    assert_eq!(
+        stream.write(b"\xff").unwrap_err().kind(),
+        ErrorKind::WouldBlock
+    );
+    assert_eq!(stream.as_str(), "a");
+    stream.next();
+    assert_eq!(stream.write(b"\xff").unwrap(), 1);
+    assert_eq!(stream.as_str(), "\\xff");
+}
+
+#[test]
+fn test_bounded_stream_fmt_write_error() {
+    let mut stream = Utf8Stream::with_capacity_bounded(3);
+    assert_eq!(std::fmt::Write::write_str(&mut stream, "red"), Ok(()));
+    assert_eq!(
+        std::fmt::Write::write_str(&mut stream, "❤️"),
+        Err(std::fmt::Error)
+    );
+}
+
+#[test]
+fn test_bounded_stream_counts_extension_left_by_reclaim() {
+    let mut stream = Utf8Stream::with_capacity_bounded(6);
+    stream.try_push("e").unwrap();
+    assert_eq!(stream.next().as_deref(), Some("e"));
+    stream.try_push("\u{301}").unwrap();
+    stream.try_push("x").unwrap();
+    stream.try_push("yzw").unwrap();
+    assert_eq!(stream.as_str(), "\u{301}xyzw");
+    assert_eq!(stream.cluster_count(), 5);
+    assert_eq!(ExactSizeIterator::len(&stream), 5);
+    for _ in 0..5 {
+        stream.pop().unwrap();
+    }
+    assert_eq!(stream.pop(), None);
+}
+
+proptest! {
+    #[test]
+    fn test_bounded_stream_counts_stay_in_sync(
+        capacity in 1usize..24,
+        operations in proptest::collection::vec(operation(), 0..32)
+    ) {
+        let mut stream = Utf8Stream::with_capacity_bounded(capacity);
+        for operation in operations {
+            match operation {
+                Operation::Push(string) => {
+                    stream.try_push(string).ok();
+                }
+                Operation::PushBytes(bytes) => {
+                    stream.push_bytes(&bytes).ok();
+                }
+                Operation::Pop => {
+                    stream.pop();
+                }
+                Operation::Truncate(length) => {
+                    if stream.is_char_boundary(length.min(stream.len())) {
+                        stream.truncate(length);
+                    }
+                }
+                Operation::Next => {
+                    stream.next();
+                }
+                Operation::NextBack => {
+                    stream.next_back();
+                }
+            }
+            prop_assert!(stream.len() <= capacity);
+            prop_assert_eq!(ExactSizeIterator::len(&stream), stream.clone().count());
+            prop_assert_eq!(
+                stream.cluster_count(),
+                stream.as_str().graphemes(true).count()
+            );
+        }
+    }
+}
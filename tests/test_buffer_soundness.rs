@@ -0,0 +1,87 @@
+//! Exercises the allocation paths of [Utf8Stream]; these tests are
+//! meant to be run under Miri as well:
+//!
+//! ```sh
+//! cargo +nightly miri test --test test_buffer_soundness
+//! ```
+use utf8_stream::Utf8Stream;
+
+#[test]
+fn test_push_grows_buffer() {
+    let mut stream = Utf8Stream::new("red");
+    for _ in 0..64 {
+        stream.push("❤️");
+    }
+    assert_eq!(stream.len(), 3 + 64 * 6);
+    assert_eq!(stream.as_str(), format!("red{}", "❤️".repeat(64)));
+}
+
+#[test]
+fn test_clone_owns_its_buffer() {
+    let mut stream = Utf8Stream::new("red");
+    let mut clone = stream.clone();
+    stream.push("❤️");
+    clone.push("heart");
+    assert_eq!(stream.as_str(), "red❤️");
+    assert_eq!(clone.as_str(), "redheart");
+    drop(stream);
+    assert_eq!(clone.as_str(), "redheart");
+}
+
+#[test]
+fn test_clone_then_drop_both() {
+    let stream = Utf8Stream::new("fire👩🏽‍🚒fighter");
+    let clones = vec![stream.clone(), stream.clone(), stream];
+    for clone in &clones {
+        assert_eq!(clone.as_str(), "fire👩🏽‍🚒fighter");
+    }
+    drop(clones);
+}
+
+#[test]
+fn test_clear_then_push() {
+    let mut stream = Utf8Stream::new("red❤️heart");
+    stream.clear();
+    assert_eq!(stream.as_str(), "");
+    stream.push("fire👩🏽‍🚒");
+    assert_eq!(stream.as_str(), "fire👩🏽‍🚒");
+    stream.clear();
+    stream.clear();
+    assert!(stream.is_empty());
+}
+
+#[test]
+fn test_push_bytes_grows_buffer() {
+    let mut stream = Utf8Stream::default();
+    for byte in "fire👩🏽‍🚒fighter".repeat(16).bytes() {
+        stream.push_bytes(&[byte]).unwrap();
+    }
+    assert_eq!(stream.as_str(), "fire👩🏽‍🚒fighter".repeat(16));
+}
+
+#[test]
+fn test_iterate_clone_after_push() {
+    let mut stream = Utf8Stream::new("red");
+    let clone = stream.clone();
+    stream.push("❤️heart");
+    assert_eq!(clone.collect::<Vec<String>>(), vec!["r", "e", "d"]);
+    assert_eq!(stream.count(), 9);
+}
+
+#[test]
+fn test_default_and_empty_streams() {
+    let stream = Utf8Stream::default();
+    assert_eq!(stream.as_str(), "");
+    assert_eq!(stream.as_bytes(), b"");
+    let stream = Utf8Stream::new("");
+    assert_eq!(stream.clone(), stream);
+}
+
+#[test]
+fn test_equality_compares_contents() {
+    let mut a = Utf8Stream::new("red");
+    let b = Utf8Stream::new("red❤️");
+    assert_ne!(a, b);
+    a.push("❤️");
+    assert_eq!(a, b);
+}
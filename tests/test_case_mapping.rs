@@ -0,0 +1,113 @@
+use utf8_stream::{CaseLocale, Utf8Stream};
+
+#[test]
+fn test_to_lowercase() {
+    let stream = Utf8Stream::new("RED❤️HEART");
+    assert_eq!(stream.to_lowercase().as_str(), "red❤️heart");
+    assert_eq!(stream.as_str(), "RED❤️HEART");
+
+    assert_eq!(Utf8Stream::new("ẞ").to_lowercase().as_str(), "ß");
+    assert_eq!(
+        Utf8Stream::new("ΟΔΟΣ ΟΔΟΣ").to_lowercase().as_str(),
+        "οδος οδος"
+    );
+    assert_eq!(Utf8Stream::new("Σ").to_lowercase().as_str(), "σ");
+    assert_eq!(Utf8Stream::new("İ").to_lowercase().as_str(), "i\u{307}");
+}
+
+#[test]
+fn test_to_uppercase() {
+    assert_eq!(
+        Utf8Stream::new("red❤️heart").to_uppercase().as_str(),
+        "RED❤️HEART"
+    );
+    assert_eq!(Utf8Stream::new("straße").to_uppercase().as_str(), "STRASSE");
+    assert_eq!(Utf8Stream::new("ﬁre").to_uppercase().as_str(), "FIRE");
+    assert_eq!(
+        Utf8Stream::new("istanbul").to_uppercase().as_str(),
+        "ISTANBUL"
+    );
+}
+
+#[test]
+fn test_to_titlecase() {
+    assert_eq!(
+        Utf8Stream::new("the QUICK brown-fox")
+            .to_titlecase()
+            .as_str(),
+        "The Quick Brown-Fox"
+    );
+    assert_eq!(
+        Utf8Stream::new("don't stop").to_titlecase().as_str(),
+        "Don't Stop"
+    );
+    assert_eq!(
+        Utf8Stream::new("ßig ﬁre").to_titlecase().as_str(),
+        "Ssig Fire"
+    );
+    assert_eq!(Utf8Stream::new("ǆungla").to_titlecase().as_str(), "ǅungla");
+    assert_eq!(Utf8Stream::new("ΟΔΟΣ").to_titlecase().as_str(), "Οδος");
+    assert_eq!(Utf8Stream::new("ᾳδω").to_titlecase().as_str(), "ᾼδω");
+}
+
+#[test]
+fn test_to_titlecase_is_grapheme_aware() {
+    assert_eq!(
+        Utf8Stream::new("e\u{301}te\u{301} E\u{301}TE\u{301}")
+            .to_titlecase()
+            .as_str(),
+        "E\u{301}te\u{301} E\u{301}te\u{301}"
+    );
+    assert_eq!(
+        Utf8Stream::new("👩🏽‍🚒fighter 42nd").to_titlecase().as_str(),
+        "👩🏽‍🚒Fighter 42nd"
+    );
+}
+
+#[test]
+fn test_case_fold() {
+    assert_eq!(Utf8Stream::new("Straße").case_fold().as_str(), "strasse");
+    assert_eq!(Utf8Stream::new("STRAẞE").case_fold().as_str(), "strasse");
+    assert_eq!(Utf8Stream::new("ΣΊΣΥΦΟΣ").case_fold().as_str(), "σίσυφοσ");
+    assert_eq!(Utf8Stream::new("σίσυφος").case_fold().as_str(), "σίσυφοσ");
+    assert_eq!(Utf8Stream::new("ﬁre").case_fold().as_str(), "fire");
+    assert_eq!(Utf8Stream::new("ıI").case_fold().as_str(), "ıi");
+    assert_eq!(Utf8Stream::new("İ").case_fold().as_str(), "i\u{307}");
+    assert_eq!(Utf8Stream::new("Ꭰꭰ").case_fold().as_str(), "ᎠᎠ");
+}
+
+#[test]
+fn test_turkic_locale() {
+    let mut stream = Utf8Stream::new("DİYARBAKIR istanbul I\u{307}");
+    assert_eq!(stream.case_locale(), CaseLocale::Neutral);
+    stream.set_case_locale(CaseLocale::Turkic);
+    assert_eq!(stream.case_locale(), CaseLocale::Turkic);
+
+    assert_eq!(stream.to_lowercase().as_str(), "diyarbakır istanbul i");
+    assert_eq!(
+        stream.to_uppercase().as_str(),
+        "DİYARBAKIR İSTANBUL I\u{307}"
+    );
+    assert_eq!(
+        stream.to_titlecase().as_str(),
+        "Diyarbakır İstanbul I\u{307}"
+    );
+    assert_eq!(stream.case_fold().as_str(), "diyarbakır istanbul ı\u{307}");
+    assert_eq!(stream.to_lowercase().case_locale(), CaseLocale::Turkic);
+}
+
+#[test]
+fn test_contains_ignore_case() {
+    let mut stream = Utf8Stream::new("Red❤️Heart STRASSE");
+    assert!(stream.contains_ignore_case("red❤️heart"));
+    assert!(stream.contains_ignore_case("straße"));
+    assert!(stream.contains_ignore_case("STRAẞE"));
+    assert!(!stream.contains_ignore_case("hearth"));
+    assert!(!stream.contains("red"));
+
+    assert!(!Utf8Stream::new("KIRMIZI").contains_ignore_case("kırmızı"));
+    stream = Utf8Stream::new("KIRMIZI");
+    stream.set_case_locale(CaseLocale::Turkic);
+    assert!(stream.contains_ignore_case("kırmızı"));
+    assert!(!stream.contains_ignore_case("kirmizi"));
+}
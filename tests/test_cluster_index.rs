@@ -0,0 +1,117 @@
+use proptest::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+use utf8_stream::Utf8Stream;
+
+mod common;
+use common::{Operation, operation};
+
+fn assert_indexed(stream: &Utf8Stream) {
+    let expected = stream.as_str().grapheme_indices(true).collect::<Vec<_>>();
+    assert_eq!(stream.cluster_count(), expected.len());
+    for (n, (offset, cluster)) in expected.into_iter().enumerate() {
+        assert_eq!(stream.nth_cluster(n), Some(cluster));
+        assert_eq!(stream.cluster_to_byte(n), Some(offset));
+        assert_eq!(stream.byte_to_cluster(offset), Some(n));
+        assert_eq!(stream.byte_to_cluster(offset + cluster.len() - 1), Some(n));
+    }
+    assert_eq!(stream.nth_cluster(stream.cluster_count()), None);
+}
+
+#[test]
+fn test_nth_cluster() {
+    let stream = Utf8Stream::new("🇧🇷🇵🇹fire👩🏽‍🚒fighter".repeat(100));
+    assert_eq!(stream.cluster_count(), 1400);
+    assert_eq!(stream.nth_cluster(995), Some("🇵🇹"));
+    assert_eq!(stream.nth_cluster(999), Some("e"));
+    assert_eq!(stream.nth_cluster(1000), Some("👩🏽‍🚒"));
+    assert_indexed(&stream);
+}
+
+#[test]
+fn test_index_follows_push() {
+    let mut stream = Utf8Stream::new("red👩");
+    assert_eq!(stream.nth_cluster(3), Some("👩"));
+    stream.push("🏽\u{200d}🚒heart");
+    assert_eq!(stream.nth_cluster(3), Some("👩🏽‍🚒"));
+    assert_eq!(stream.nth_cluster(4), Some("h"));
+    stream.push_bytes(&[240, 159]).unwrap();
+    assert_eq!(stream.cluster_count(), 9);
+    stream.push_bytes(&[148, 165]).unwrap();
+    assert_eq!(stream.nth_cluster(9), Some("🔥"));
+    assert_indexed(&stream);
+}
+
+#[test]
+fn test_index_follows_pop_and_truncate() {
+    let mut stream = Utf8Stream::new("red❤️heart");
+    assert_eq!(stream.byte_to_cluster(13), Some(8));
+    stream.pop();
+    assert_eq!(stream.byte_to_cluster(13), None);
+    assert_eq!(stream.byte_to_cluster(12), Some(7));
+    stream.truncate(9);
+    assert_eq!(stream.cluster_to_byte(4), Some(9));
+    assert_eq!(stream.nth_cluster(4), None);
+    stream.truncate(6);
+    assert_eq!(stream.nth_cluster(3), Some("❤"));
+    stream.push("\u{fe0f}");
+    assert_eq!(stream.nth_cluster(3), Some("❤️"));
+    assert_indexed(&stream);
+}
+
+#[test]
+fn test_truncate_moves_cursors() {
+    let mut stream = Utf8Stream::new("red❤️heart");
+    stream.advance_by(5).unwrap();
+    stream.truncate(3);
+    assert_eq!(stream.position(), 3);
+    assert_eq!(stream.next(), None);
+    stream.push("❤️");
+    assert_eq!(stream.next().as_deref(), Some("❤️"));
+
+    let mut stream = Utf8Stream::new("red❤️heart");
+    stream.next_back();
+    stream.next_back();
+    stream.truncate(10);
+    assert_eq!(stream.remaining_clusters(), 5);
+    assert_eq!(
+        stream.rev().collect::<Vec<String>>(),
+        vec!["h", "❤️", "d", "e", "r"]
+    );
+}
+
+#[test]
+#[should_panic(expected = "byte index 4 is not a char boundary")]
+fn test_truncate_panics_within_char() {
+    Utf8Stream::new("red❤️heart").truncate(4);
+}
+
+proptest! {
+    #[test]
+    fn test_index_stays_in_sync(
+        operations in proptest::collection::vec(operation(), 0..24)
+    ) {
+        let mut stream = Utf8Stream::default();
+        stream.nth_cluster(0);
+        for operation in operations {
+            match operation {
+                Operation::Push(string) => stream.push(string),
+                Operation::PushBytes(bytes) => stream.push_bytes(&bytes).unwrap(),
+                Operation::Pop => {
+                    stream.pop();
+                }
+                Operation::Truncate(length) => {
+                    if stream.is_char_boundary(length.min(stream.len())) {
+                        stream.truncate(length);
+                    }
+                }
+                Operation::Next => {
+                    stream.next();
+                }
+                Operation::NextBack => {
+                    stream.next_back();
+                }
+            }
+            assert_indexed(&stream);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Borrowed slices must not outlive the [Utf8Stream] they came from,
+//! nor be held across a mutation of its buffer.
+//!
+//! Regenerate the expected diagnostics with:
+//!
+//! ```sh
+//! TRYBUILD=overwrite cargo test --test test_compile_fail
+//! ```
+//!
+//! [Utf8Stream]: utf8_stream::Utf8Stream
+
+#[test]
+fn test_compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile-fail/*.rs");
+}
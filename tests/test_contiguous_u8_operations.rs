@@ -1,4 +1,4 @@
-use utf8_stream::{Utf8Stream};
+use utf8_stream::Utf8Stream;
 
 // #[test]
 // fn test_push() {
@@ -16,19 +16,19 @@ use utf8_stream::{Utf8Stream};
 // fn test_pop() {
 //     let mut stream = Utf8Stream::new("test");
 //     assert_eq!(stream.as_str(), "test");
-//     assert_eq!(stream.pop(), Some("t"));
-//     assert_eq!(stream.pop(), Some("s"));
-//     assert_eq!(stream.pop(), Some("e"));
+//     assert_eq!(stream.pop().as_deref(), Some("t"));
+//     assert_eq!(stream.pop().as_deref(), Some("s"));
+//     assert_eq!(stream.pop().as_deref(), Some("e"));
 // }
 
 // #[test]
 // fn test_pop_utf8_short() {
 //     let mut stream = Utf8Stream::new("d❤️h");
 //     assert_eq!(stream.as_str(), "d❤️h");
-//     assert_eq!(stream.pop(), Some("h"));
-//     assert_eq!(stream.pop(), Some("❤️"));
-//     assert_eq!(stream.pop(), Some("d"));
-//     assert_eq!(stream.pop(), None);
+//     assert_eq!(stream.pop().as_deref(), Some("h"));
+//     assert_eq!(stream.pop().as_deref(), Some("❤️"));
+//     assert_eq!(stream.pop().as_deref(), Some("d"));
+//     assert_eq!(stream.pop().as_deref(), None);
 // }
 
 // #[test]
@@ -39,28 +39,28 @@ use utf8_stream::{Utf8Stream};
 //         stream.as_bytes(),
 //         &[114, 101, 100, 226, 157, 164, 239, 184, 143, 104, 101, 97, 114, 116]
 //     );
-//     assert_eq!(stream.pop(), Some("t"));
-//     assert_eq!(stream.pop(), Some("r"));
-//     assert_eq!(stream.pop(), Some("a"));
-//     assert_eq!(stream.pop(), Some("e"));
-//     assert_eq!(stream.pop(), Some("h"));
-//     assert_eq!(stream.pop(), Some("❤️"));
-//     assert_eq!(stream.pop(), Some("d"));
-//     assert_eq!(stream.pop(), Some("e"));
-//     assert_eq!(stream.pop(), Some("r"));
-//     assert_eq!(stream.pop(), None);
+//     assert_eq!(stream.pop().as_deref(), Some("t"));
+//     assert_eq!(stream.pop().as_deref(), Some("r"));
+//     assert_eq!(stream.pop().as_deref(), Some("a"));
+//     assert_eq!(stream.pop().as_deref(), Some("e"));
+//     assert_eq!(stream.pop().as_deref(), Some("h"));
+//     assert_eq!(stream.pop().as_deref(), Some("❤️"));
+//     assert_eq!(stream.pop().as_deref(), Some("d"));
+//     assert_eq!(stream.pop().as_deref(), Some("e"));
+//     assert_eq!(stream.pop().as_deref(), Some("r"));
+//     assert_eq!(stream.pop().as_deref(), None);
 // }
 
 // #[test]
 // fn test_clear() {
 //     let mut stream = Utf8Stream::new("test");
 
-//     assert_eq!(stream.next(), Some("t"),);
-//     assert_eq!(stream.next(), Some("e"),);
+//     assert_eq!(stream.next().as_deref(), Some("t"),);
+//     assert_eq!(stream.next().as_deref(), Some("e"),);
 //     stream.clear();
-//     assert_eq!(stream.next(), None,);
+//     assert_eq!(stream.next().as_deref(), None,);
 //     stream.push("s");
-//     assert_eq!(stream.next(), Some("s"),);
+//     assert_eq!(stream.next().as_deref(), Some("s"),);
 // }
 
 #[test]
@@ -69,17 +69,17 @@ fn test_rewind() {
 
     assert_eq!(stream.get(0), Some("r"));
     assert_eq!(stream.get(3), Some("❤️"));
-    assert_eq!(stream.next(), Some("r"));
-    assert_eq!(stream.next(), Some("e"));
-    assert_eq!(stream.next(), Some("d"));
-    assert_eq!(stream.next(), Some("❤️"));
+    assert_eq!(stream.next().as_deref(), Some("r"));
+    assert_eq!(stream.next().as_deref(), Some("e"));
+    assert_eq!(stream.next().as_deref(), Some("d"));
+    assert_eq!(stream.next().as_deref(), Some("❤️"));
     stream.rewind();
     assert_eq!(stream.get(0), Some("r"));
     assert_eq!(stream.get(3), Some("❤️"));
-    assert_eq!(stream.next(), Some("r"));
-    assert_eq!(stream.next(), Some("e"));
-    assert_eq!(stream.next(), Some("d"));
-    assert_eq!(stream.next(), Some("❤️"));
+    assert_eq!(stream.next().as_deref(), Some("r"));
+    assert_eq!(stream.next().as_deref(), Some("e"));
+    assert_eq!(stream.next().as_deref(), Some("d"));
+    assert_eq!(stream.next().as_deref(), Some("❤️"));
 }
 
 // #[test]
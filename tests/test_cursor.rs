@@ -0,0 +1,75 @@
+use std::io::SeekFrom;
+use std::num::NonZeroUsize;
+
+use utf8_stream::{Utf8Stream, Utf8StreamError};
+
+#[test]
+fn test_seek_snaps_to_cluster_boundary() {
+    let mut stream = Utf8Stream::new("fire👩🏽‍🚒fighter");
+    assert_eq!(stream.seek(SeekFrom::Start(5)), Ok(4));
+    assert_eq!(stream.peek(), Some("👩🏽‍🚒"));
+    assert_eq!(stream.seek(SeekFrom::Start(16)), Ok(19));
+    assert_eq!(stream.peek(), Some("f"));
+    assert_eq!(stream.seek(SeekFrom::Current(-3)), Ok(19));
+    assert_eq!(stream.seek(SeekFrom::End(0)), Ok(26));
+    assert_eq!(stream.peek(), None);
+    assert_eq!(stream.seek(SeekFrom::Start(0)), Ok(0));
+    assert_eq!(stream.peek(), Some("f"));
+}
+
+#[test]
+fn test_seek_out_of_range() {
+    let mut stream = Utf8Stream::new("red");
+    stream.next();
+    assert_eq!(
+        stream.seek(SeekFrom::Current(-2)),
+        Err(Utf8StreamError::IndexOutOfRange {
+            index: 0,
+            length: 3
+        })
+    );
+    assert_eq!(
+        stream.seek(SeekFrom::Start(4)),
+        Err(Utf8StreamError::IndexOutOfRange {
+            index: 4,
+            length: 3
+        })
+    );
+    assert_eq!(stream.position(), 1);
+}
+
+#[test]
+fn test_peek_does_not_advance() {
+    let mut stream = Utf8Stream::new("🇧🇷🇵🇹");
+    assert_eq!(stream.peek(), Some("🇧🇷"));
+    assert_eq!(stream.peek_nth(1), Some("🇵🇹"));
+    assert_eq!(stream.peek_nth(2), None);
+    assert_eq!(stream.position(), 0);
+    assert_eq!(stream.next().as_deref(), Some("🇧🇷"));
+    assert_eq!(stream.peek(), Some("🇵🇹"));
+}
+
+#[test]
+fn test_advance_by_as_lexer() {
+    let mut stream = Utf8Stream::new("let ❤️ = 1;");
+    let mut tokens = Vec::new();
+    while let Some(cluster) = stream.peek() {
+        if cluster == " " {
+            stream.advance_by(1).unwrap();
+            continue;
+        }
+        let start = stream.position();
+        let mut length = 0;
+        while stream
+            .peek_nth(length)
+            .is_some_and(|cluster| cluster != " ")
+        {
+            length += 1;
+        }
+        stream.advance_by(length).unwrap();
+        tokens.push(stream[start..stream.position()].to_string());
+    }
+    assert_eq!(tokens, vec!["let", "❤️", "=", "1;"]);
+    assert_eq!(stream.advance_by(0), Ok(()));
+    assert_eq!(stream.advance_by(2), Err(NonZeroUsize::new(2).unwrap()));
+}
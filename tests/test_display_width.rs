@@ -0,0 +1,66 @@
+use std::borrow::Cow;
+
+use utf8_stream::Utf8Stream;
+
+#[test]
+fn test_display_width() {
+    assert_eq!(Utf8Stream::new("red").display_width(), 3);
+    assert_eq!(Utf8Stream::new("❤").display_width(), 1);
+    assert_eq!(Utf8Stream::new("❤️").display_width(), 2);
+    assert_eq!(Utf8Stream::new("👩🏽‍🚒").display_width(), 2);
+    assert_eq!(Utf8Stream::new("🇧🇷🇵🇹").display_width(), 4);
+    assert_eq!(Utf8Stream::new("漢字").display_width(), 4);
+    assert_eq!(Utf8Stream::new("e\u{301}").display_width(), 1);
+    assert_eq!(Utf8Stream::new("").display_width(), 0);
+}
+
+#[test]
+fn test_display_width_starts_at_cursor() {
+    let mut stream = Utf8Stream::new("👩🏽‍🚒red");
+    stream.next();
+    assert_eq!(stream.display_width(), 3);
+}
+
+#[test]
+fn test_truncate_to_width() {
+    let stream = Utf8Stream::new("漢字❤️heart");
+    assert!(matches!(
+        stream.truncate_to_width(11, "…"),
+        Cow::Borrowed("漢字❤️heart")
+    ));
+    assert_eq!(stream.truncate_to_width(10, "…"), "漢字❤️hea…");
+    assert_eq!(stream.truncate_to_width(5, "..."), "漢...");
+    assert_eq!(stream.truncate_to_width(4, "..."), "...");
+    assert_eq!(stream.truncate_to_width(3, "…"), "漢…");
+    assert_eq!(stream.truncate_to_width(2, "…"), "…");
+    assert_eq!(stream.truncate_to_width(0, "…"), "…");
+    assert_eq!(stream.truncate_to_width(0, ""), "");
+}
+
+#[test]
+fn test_wrap() {
+    let stream = Utf8Stream::new("red❤️heart\r\n\nfire👩🏽‍🚒fighter");
+    assert_eq!(
+        stream.wrap(4).collect::<Vec<&str>>(),
+        vec!["red", "❤️he", "art", "", "fire", "👩🏽‍🚒fi", "ghte", "r"]
+    );
+}
+
+#[test]
+fn test_wrap_narrower_than_cluster() {
+    let stream = Utf8Stream::new("漢a字");
+    assert_eq!(stream.wrap(1).collect::<Vec<&str>>(), vec!["漢", "a", "字"]);
+    assert_eq!(stream.wrap(0).collect::<Vec<&str>>(), vec!["漢", "a", "字"]);
+}
+
+#[test]
+fn test_wrap_lines_fit_columns() {
+    let stream = Utf8Stream::new("🇧🇷🇵🇹 fire👩🏽‍🚒fighter 漢字 e\u{301}".repeat(8));
+    for columns in 2..16 {
+        let lines = stream.wrap(columns).collect::<Vec<&str>>();
+        assert_eq!(lines.concat(), stream.as_str());
+        for line in lines {
+            assert!(Utf8Stream::borrowed(line).display_width() <= columns);
+        }
+    }
+}
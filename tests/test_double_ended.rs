@@ -0,0 +1,137 @@
+use std::io::SeekFrom;
+
+use proptest::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+use utf8_stream::Utf8Stream;
+
+mod common;
+use common::{CLUSTER_PIECES, text};
+
+proptest! {
+    #[test]
+    fn test_rev_matches_grapheme_reversal(text in text(CLUSTER_PIECES, 32)) {
+        let stream = Utf8Stream::new(&text);
+        prop_assert_eq!(
+            stream.rev().collect::<Vec<String>>(),
+            text.graphemes(true).rev().collect::<Vec<&str>>()
+        );
+    }
+
+    #[test]
+    fn test_rev_matches_chars_reversal(text in text(CLUSTER_PIECES, 32)) {
+        let stream = Utf8Stream::new(&text);
+        prop_assert_eq!(
+            stream
+                .rev()
+                .flat_map(|cluster| cluster.chars().rev().collect::<Vec<char>>())
+                .collect::<Vec<char>>(),
+            text.chars().rev().collect::<Vec<char>>()
+        );
+    }
+
+    #[test]
+    fn test_len_matches_count_across_push_and_seek(
+        parts in proptest::collection::vec("[a-z❤\u{fe0f}\u{301}\u{200d}👩🏽🚒🇧🇷]{0,3}", 1..12),
+        steps in proptest::collection::vec((0u8..6, any::<u64>()), 0..48)
+    ) {
+        let mut parts = parts.into_iter();
+        let mut stream = Utf8Stream::new(parts.next().unwrap_or_default());
+        for (step, offset) in steps {
+            match step {
+                0 => {
+                    stream.next();
+                }
+                1 => {
+                    stream.next_back();
+                }
+                2 => {
+                    if let Some(part) = parts.next() {
+                        stream.push(part);
+                    }
+                }
+                3 => {
+                    let offset = offset % (stream.len() as u64 + 1);
+                    stream.seek(SeekFrom::Start(offset)).unwrap();
+                }
+                4 => {
+                    let offset = offset % (stream.len() as u64 + 1);
+                    stream.seek(SeekFrom::End(-(offset as i64))).unwrap();
+                }
+                _ => {
+                    stream.pop();
+                }
+            }
+            prop_assert_eq!(ExactSizeIterator::len(&stream), stream.clone().count());
+        }
+    }
+
+    #[test]
+    fn test_mixed_ends_yield_each_cluster_once(
+        text in text(CLUSTER_PIECES, 32),
+        steps in proptest::collection::vec(any::<bool>(), 0..48)
+    ) {
+        let mut stream = Utf8Stream::new(&text);
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        for forward in steps {
+            if forward {
+                front.extend(stream.next());
+            } else {
+                back.extend(stream.next_back());
+            }
+        }
+        prop_assert_eq!(
+            ExactSizeIterator::len(&stream),
+            text.graphemes(true).count() - front.len() - back.len()
+        );
+        front.extend(stream.by_ref());
+        front.extend(back.into_iter().rev());
+        prop_assert_eq!(front, text.graphemes(true).collect::<Vec<&str>>());
+    }
+}
+
+#[test]
+fn test_next_back_starts_at_the_end() {
+    let mut stream = Utf8Stream::new("red❤️heart");
+    assert_eq!(stream.next().as_deref(), Some("r"));
+    assert_eq!(stream.next_back().as_deref(), Some("t"));
+    assert_eq!(stream.next_back().as_deref(), Some("r"));
+    assert_eq!(stream.next().as_deref(), Some("e"));
+    assert_eq!(
+        stream.collect::<Vec<String>>(),
+        vec!["d", "❤️", "h", "e", "a"]
+    );
+}
+
+#[test]
+fn test_len_after_seek_behind_extension_taken_from_back() {
+    let mut stream = Utf8Stream::new("e");
+    assert_eq!(stream.next().as_deref(), Some("e"));
+    stream.push("\u{301}");
+    assert_eq!(stream.next_back().as_deref(), Some("\u{301}"));
+    stream.seek(SeekFrom::Start(0)).unwrap();
+    assert_eq!(ExactSizeIterator::len(&stream), 1);
+    assert_eq!(stream.next().as_deref(), Some("e"));
+    assert_eq!(ExactSizeIterator::len(&stream), 0);
+}
+
+#[test]
+fn test_seek_stops_at_back_cursor() {
+    let mut stream = Utf8Stream::new("ab");
+    assert_eq!(stream.next_back().as_deref(), Some("b"));
+    assert_eq!(stream.seek(SeekFrom::End(0)), Ok(1));
+    assert_eq!(stream.pop().as_deref(), Some("b"));
+    stream.seek(SeekFrom::Start(0)).unwrap();
+    assert_eq!(ExactSizeIterator::len(&stream), 1);
+    assert_eq!(stream.collect::<Vec<String>>(), vec!["a"]);
+}
+
+#[test]
+fn test_back_cursor_follows_push_until_moved() {
+    let mut stream = Utf8Stream::new("red");
+    stream.push("❤️");
+    assert_eq!(stream.next_back().as_deref(), Some("❤️"));
+    stream.push("heart");
+    assert_eq!(stream.next_back().as_deref(), Some("d"));
+    assert_eq!(stream.collect::<Vec<String>>(), vec!["r", "e"]);
+}
@@ -0,0 +1,107 @@
+use std::io::SeekFrom;
+
+use utf8_stream::Utf8Stream;
+
+#[test]
+fn test_len_counts_remaining_clusters() {
+    let mut stream = Utf8Stream::new("fire👩🏽‍🚒fighter");
+    assert_eq!(ExactSizeIterator::len(&stream), 12);
+    assert_eq!(stream.size_hint(), (12, Some(12)));
+    assert_eq!(stream.len(), 26);
+    for remaining in (0..12).rev() {
+        stream.next().unwrap();
+        assert_eq!(ExactSizeIterator::len(&stream), remaining);
+    }
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_len_from_both_ends() {
+    let mut stream = Utf8Stream::new("red❤️heart");
+    stream.next();
+    stream.next_back();
+    stream.next_back();
+    assert_eq!(stream.remaining_clusters(), 6);
+    let rest = stream.collect::<Vec<String>>();
+    assert_eq!(rest.len(), 6);
+}
+
+#[test]
+fn test_len_follows_push_and_pop() {
+    let mut stream = Utf8Stream::new("👩");
+    assert_eq!(stream.remaining_clusters(), 1);
+    stream.push("🏽\u{200d}");
+    assert_eq!(stream.remaining_clusters(), 1);
+    stream.push("🚒🇧");
+    assert_eq!(stream.remaining_clusters(), 2);
+    stream.push_bytes("🇷🇵".as_bytes()).unwrap();
+    assert_eq!(stream.remaining_clusters(), 3);
+    assert_eq!(stream.pop().as_deref(), Some("🇵"));
+    assert_eq!(stream.remaining_clusters(), 2);
+    assert_eq!(stream.next().as_deref(), Some("👩🏽‍🚒"));
+    assert_eq!(stream.pop().as_deref(), Some("🇧🇷"));
+    assert_eq!(stream.remaining_clusters(), 0);
+    assert_eq!(stream.pop().as_deref(), Some("👩🏽‍🚒"));
+    assert_eq!(stream.remaining_clusters(), 0);
+    stream.push("red");
+    assert_eq!(stream.remaining_clusters(), 3);
+}
+
+#[test]
+fn test_len_after_seek_rewind_and_clear() {
+    let mut stream = Utf8Stream::new("red❤️heart");
+    stream.seek(SeekFrom::Start(9)).unwrap();
+    assert_eq!(stream.remaining_clusters(), 5);
+    stream.rewind();
+    assert_eq!(stream.remaining_clusters(), 9);
+    stream.clear();
+    assert_eq!(stream.remaining_clusters(), 0);
+}
+
+#[test]
+fn test_len_after_reclaiming_consumed_clusters() {
+    let mut stream = Utf8Stream::with_capacity_bounded(8);
+    stream.try_push("red❤").unwrap();
+    stream.next();
+    stream.next();
+    stream.try_push("hea").unwrap();
+    assert_eq!(stream.remaining_clusters(), 5);
+    assert_eq!(stream.collect::<Vec<String>>().len(), 5);
+}
+
+#[test]
+fn test_graphemes_exact_size() {
+    let mut stream = Utf8Stream::new("fire👩🏽‍🚒fighter");
+    stream.next();
+    stream.next_back();
+    let mut graphemes = stream.graphemes();
+    assert_eq!(graphemes.len(), 10);
+    graphemes.next();
+    graphemes.next_back();
+    assert_eq!(graphemes.len(), 8);
+    assert_eq!(stream.bytes().len(), 24);
+}
+
+#[test]
+fn test_zip_and_collect_use_cluster_count() {
+    let stream = Utf8Stream::new("red❤️");
+    let zipped = stream.zip(1..).collect::<Vec<(String, usize)>>();
+    assert_eq!(zipped.last(), Some(&("❤️".to_string(), 4)));
+    assert_eq!(zipped.len(), 4);
+}
+
+#[test]
+fn test_graphemes_exact_size_after_push_extends_cluster() {
+    let mut stream = Utf8Stream::new("red❤️");
+    for _ in 0..4 {
+        stream.next();
+    }
+    stream.push("\u{200d}🔥");
+    let mut graphemes = stream.graphemes();
+    assert_eq!(graphemes.len(), graphemes.clone().count());
+    graphemes.next();
+    assert_eq!(graphemes.len(), graphemes.clone().count());
+    graphemes.next_back();
+    assert_eq!(graphemes.len(), 0);
+    assert_eq!(graphemes.next(), None);
+}
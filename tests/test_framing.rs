@@ -0,0 +1,215 @@
+use proptest::prelude::*;
+use utf8_stream::{FrameDecoder, Framing, InvalidPolicy, Utf8StreamError};
+
+fn decode(decoder: &mut FrameDecoder) -> Vec<Result<String, Utf8StreamError>> {
+    decoder.by_ref().collect()
+}
+
+#[test]
+fn test_netstring_encode() {
+    assert_eq!(Framing::Netstring.encode("").unwrap(), b"0:,");
+    assert_eq!(Framing::Netstring.encode("red").unwrap(), b"3:red,");
+    assert_eq!(
+        Framing::Netstring.encode("❤️").unwrap(),
+        b"6:\xe2\x9d\xa4\xef\xb8\x8f,"
+    );
+}
+
+#[test]
+fn test_length_prefixed_encode() {
+    assert_eq!(Framing::LengthPrefixed.encode("").unwrap(), b"\0\0\0\0");
+    assert_eq!(
+        Framing::LengthPrefixed.encode("❤️").unwrap(),
+        b"\0\0\0\x06\xe2\x9d\xa4\xef\xb8\x8f"
+    );
+}
+
+#[test]
+fn test_netstring_decoder() {
+    let mut decoder = FrameDecoder::new(Framing::Netstring);
+    assert_eq!(decoder.framing(), Framing::Netstring);
+    decoder.push(b"3:red,0:,1");
+    assert_eq!(
+        decode(&mut decoder),
+        vec![Ok("red".to_string()), Ok(String::new())]
+    );
+    assert_eq!(decoder.pending(), b"1");
+    decoder.push(b"1:firefig");
+    assert_eq!(decode(&mut decoder), vec![]);
+    decoder.push(b"hter,");
+    assert_eq!(decode(&mut decoder), vec![Ok("firefighter".to_string())]);
+    assert_eq!(decoder.pending(), b"");
+}
+
+#[test]
+fn test_length_prefixed_decoder() {
+    let mut decoder = FrameDecoder::new(Framing::LengthPrefixed);
+    decoder.push(b"\0\0\0\x03red\0\0");
+    assert_eq!(decode(&mut decoder), vec![Ok("red".to_string())]);
+    decoder.push(b"\0\x06\xe2\x9d\xa4\xef");
+    assert_eq!(decode(&mut decoder), vec![]);
+    decoder.push(b"\xb8\x8f");
+    assert_eq!(decode(&mut decoder), vec![Ok("❤️".to_string())]);
+}
+
+#[test]
+fn test_strict_decoder_rejects_invalid_utf8() {
+    let mut decoder = FrameDecoder::new(Framing::Netstring).policy(InvalidPolicy::Strict);
+    decoder.push(b"3:red,5:re\xffd!,2:\xe2\x9d,5:heart,");
+    assert_eq!(
+        decode(&mut decoder),
+        vec![
+            Ok("red".to_string()),
+            Err(Utf8StreamError::InvalidSequence {
+                offset: 10,
+                length: 1
+            }),
+            Err(Utf8StreamError::IncompleteTail {
+                offset: 16,
+                bytes: vec![0xe2, 0x9d]
+            }),
+            Ok("heart".to_string()),
+        ]
+    );
+
+    let mut decoder = FrameDecoder::new(Framing::LengthPrefixed).policy(InvalidPolicy::Strict);
+    decoder.push(b"\0\0\0\x01\xff\0\0\0\x01!");
+    assert_eq!(
+        decode(&mut decoder),
+        vec![
+            Err(Utf8StreamError::InvalidSequence {
+                offset: 4,
+                length: 1
+            }),
+            Ok("!".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_decoder_handles_invalid_utf8_as_per_policy() {
+    let input = b"3:red,5:re\xffd!,2:\xe2\x9d,5:heart,";
+    let mut decoder = FrameDecoder::new(Framing::Netstring);
+    decoder.push(input);
+    assert_eq!(
+        decode(&mut decoder),
+        vec![
+            Ok("red".to_string()),
+            Ok("re\u{fffd}d!".to_string()),
+            Ok("\u{fffd}".to_string()),
+            Ok("heart".to_string()),
+        ]
+    );
+
+    let mut decoder = FrameDecoder::new(Framing::Netstring).policy(InvalidPolicy::Escape);
+    decoder.push(input);
+    assert_eq!(
+        decode(&mut decoder),
+        vec![
+            Ok("red".to_string()),
+            Ok("re\\xffd!".to_string()),
+            Ok("\\xe2\\x9d".to_string()),
+            Ok("heart".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_decoder_bounds_replacements_to_max_length() {
+    let mut decoder = FrameDecoder::new(Framing::Netstring)
+        .policy(InvalidPolicy::Escape)
+        .max_length(4);
+    decoder.push(b"4:re\xffd,4:\xf0\x9f\x94\xa5,");
+    assert_eq!(
+        decode(&mut decoder),
+        vec![
+            Err(Utf8StreamError::CapacityExceeded {
+                capacity: 4,
+                length: 7
+            }),
+            Ok("🔥".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_decoder_max_length() {
+    let mut decoder = FrameDecoder::new(Framing::Netstring).max_length(5);
+    decoder.push(b"5:heart,11:fire");
+    assert_eq!(
+        decode(&mut decoder),
+        vec![
+            Ok("heart".to_string()),
+            Err(Utf8StreamError::FrameTooLong {
+                limit: 5,
+                length: 11
+            })
+        ]
+    );
+    assert_eq!(decoder.pending(), b"");
+    decoder.push(b"fighter,3:r");
+    assert_eq!(decoder.pending(), b"3:r");
+    decoder.push(b"ed,");
+    assert_eq!(decode(&mut decoder), vec![Ok("red".to_string())]);
+
+    let mut decoder = FrameDecoder::new(Framing::LengthPrefixed).max_length(2);
+    decoder.push(b"\0\0\0\x03red\0\0\0\x02ok");
+    assert_eq!(
+        decode(&mut decoder),
+        vec![
+            Err(Utf8StreamError::FrameTooLong {
+                limit: 2,
+                length: 3
+            }),
+            Ok("ok".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_malformed_netstrings() {
+    for (input, offset) in [
+        (&b"3:red;"[..], 5),
+        (b"3red,", 1),
+        (b":red,", 0),
+        (b"03:red,", 0),
+        (b"-3:red,", 0),
+        (b"99999999999999999999999:", 0),
+        (b"3:red,x", 6),
+    ] {
+        let mut decoder = FrameDecoder::new(Framing::Netstring);
+        decoder.push(input);
+        let results = decode(&mut decoder);
+        assert_eq!(
+            results.last(),
+            Some(&Err(Utf8StreamError::MalformedFrame { offset })),
+            "{input:?}"
+        );
+        decoder.push(b"3:red,");
+        assert_eq!(decoder.next(), None);
+    }
+}
+
+fn frames() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec(any::<String>(), 0..8)
+}
+
+proptest! {
+    #[test]
+    fn test_round_trip(frames in frames(), chunk in 1..16usize) {
+        for framing in [Framing::Netstring, Framing::LengthPrefixed] {
+            let encoded: Vec<u8> = frames
+                .iter()
+                .flat_map(|frame| framing.encode(frame).unwrap())
+                .collect();
+            let mut decoder = FrameDecoder::new(framing);
+            let mut decoded = Vec::new();
+            for bytes in encoded.chunks(chunk) {
+                decoder.push(bytes);
+                decoded.extend(decoder.by_ref().map(Result::unwrap));
+            }
+            prop_assert_eq!(&decoded, &frames);
+            prop_assert!(decoder.pending().is_empty());
+        }
+    }
+}
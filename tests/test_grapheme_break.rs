@@ -0,0 +1,94 @@
+use utf8_stream::Utf8Stream;
+
+fn grapheme_break_tests() -> Vec<(String, Vec<String>)> {
+    include_str!("GraphemeBreakTest.txt")
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut clusters = Vec::<String>::new();
+            let mut cluster = String::new();
+            for token in line.split_whitespace() {
+                match token {
+                    "÷" => {
+                        if !cluster.is_empty() {
+                            clusters.push(std::mem::take(&mut cluster));
+                        }
+                    }
+                    "×" => {}
+                    hex => {
+                        cluster.push(char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap())
+                    }
+                }
+            }
+            (clusters.concat(), clusters)
+        })
+        .collect()
+}
+
+#[test]
+fn test_grapheme_break_test_data_is_loaded() {
+    assert!(grapheme_break_tests().len() > 700);
+}
+
+#[test]
+fn test_iterator_conforms_to_grapheme_break_test() {
+    for (string, clusters) in grapheme_break_tests() {
+        let stream = Utf8Stream::new(&string);
+        assert_eq!(stream.collect::<Vec<String>>(), clusters, "{string:?}");
+    }
+}
+
+#[test]
+fn test_pop_conforms_to_grapheme_break_test() {
+    for (string, clusters) in grapheme_break_tests() {
+        let mut stream = Utf8Stream::new(&string);
+        let mut popped = Vec::<String>::new();
+        while let Some(cluster) = stream.pop() {
+            popped.push(cluster.to_string());
+        }
+        popped.reverse();
+        assert_eq!(popped, clusters, "{string:?}");
+    }
+}
+
+#[test]
+fn test_get_conforms_to_grapheme_break_test() {
+    for (string, clusters) in grapheme_break_tests() {
+        let stream = Utf8Stream::new(&string);
+        let mut index = 0;
+        for cluster in clusters {
+            if cluster != "\0" {
+                for byte in index..index + cluster.len() {
+                    assert_eq!(stream.get(byte), Some(cluster.as_str()), "{string:?}");
+                }
+            }
+            index += cluster.len();
+        }
+        assert_eq!(stream.get(index), None, "{string:?}");
+    }
+}
+
+#[test]
+fn test_combining_marks_after_ascii_letters() {
+    let stream = Utf8Stream::new("cafe\u{301} na\u{303}o");
+    assert_eq!(
+        stream.collect::<Vec<String>>(),
+        vec!["c", "a", "f", "e\u{301}", " ", "n", "a\u{303}", "o"]
+    );
+}
+
+#[test]
+fn test_adjacent_emoji() {
+    let stream = Utf8Stream::new("❤️👩🏽‍🚒❤️");
+    assert_eq!(stream.collect::<Vec<String>>(), vec!["❤️", "👩🏽‍🚒", "❤️"]);
+}
+
+#[test]
+fn test_regional_indicator_flags() {
+    let mut stream = Utf8Stream::new("🇧🇷🇵🇹🇯");
+    assert_eq!(stream.pop().as_deref(), Some("🇯"));
+    assert_eq!(stream.pop().as_deref(), Some("🇵🇹"));
+    assert_eq!(stream.pop().as_deref(), Some("🇧🇷"));
+    assert_eq!(stream.pop().as_deref(), None);
+}
@@ -0,0 +1,127 @@
+use utf8_stream::{InvalidPolicy, Utf8Stream, Utf8StreamError};
+
+const MAXIMAL_SUBPARTS: &[u8] = b"\x61\xF1\x80\x80\xE1\x80\xC2\x62\x80\x63\x80\xBF\x64";
+
+#[test]
+fn test_default_policy_is_replace() {
+    let stream = Utf8Stream::default();
+    assert_eq!(stream.policy(), InvalidPolicy::Replace);
+}
+
+#[test]
+fn test_replace_maximal_subparts() {
+    let mut stream = Utf8Stream::with_policy(InvalidPolicy::Replace);
+    stream.push_bytes(MAXIMAL_SUBPARTS).unwrap();
+    assert_eq!(
+        stream.as_str(),
+        "a\u{fffd}\u{fffd}\u{fffd}b\u{fffd}c\u{fffd}\u{fffd}d"
+    );
+    assert_eq!(stream.as_str(), String::from_utf8_lossy(MAXIMAL_SUBPARTS));
+}
+
+#[test]
+fn test_replace_maximal_subparts_byte_by_byte() {
+    let mut stream = Utf8Stream::with_policy(InvalidPolicy::Replace);
+    for byte in MAXIMAL_SUBPARTS {
+        stream.push_bytes(&[*byte]).unwrap();
+    }
+    stream.finish().unwrap();
+    assert_eq!(stream.as_str(), String::from_utf8_lossy(MAXIMAL_SUBPARTS));
+}
+
+#[test]
+fn test_skip() {
+    let mut stream = Utf8Stream::with_policy(InvalidPolicy::Skip);
+    stream.push_bytes(MAXIMAL_SUBPARTS).unwrap();
+    stream.push_bytes(&[226, 157]).unwrap();
+    stream.finish().unwrap();
+    assert_eq!(stream.as_str(), "abcd");
+}
+
+#[test]
+fn test_escape() {
+    let mut stream = Utf8Stream::with_policy(InvalidPolicy::Escape);
+    stream.push_bytes(b"red\xff\xe2\x9dheart").unwrap();
+    stream.push_bytes(&[240, 159]).unwrap();
+    stream.finish().unwrap();
+    assert_eq!(stream.as_str(), "red\\xff\\xe2\\x9dheart\\xf0\\x9f");
+}
+
+#[test]
+fn test_strict_reports_offset_of_invalid_sequence() {
+    let mut stream = Utf8Stream::with_policy(InvalidPolicy::Strict);
+    stream.push_bytes("red❤️".as_bytes()).unwrap();
+    stream.push_bytes(&[104, 226]).unwrap();
+    assert_eq!(
+        stream.push_bytes(&[157, 101, 97]),
+        Err(Utf8StreamError::InvalidSequence {
+            offset: 10,
+            length: 2
+        })
+    );
+    assert_eq!(stream.as_str(), "red❤️h");
+    assert_eq!(stream.pending(), &[]);
+}
+
+#[test]
+fn test_strict_reports_incomplete_tail() {
+    let mut stream = Utf8Stream::with_policy(InvalidPolicy::Strict);
+    stream.push_bytes(&[114, 101, 100, 240, 159]).unwrap();
+    assert_eq!(
+        stream.finish(),
+        Err(Utf8StreamError::IncompleteTail {
+            offset: 3,
+            bytes: vec![240, 159]
+        })
+    );
+    assert_eq!(stream.as_str(), "red");
+    assert_eq!(
+        Utf8StreamError::IncompleteTail {
+            offset: 3,
+            bytes: vec![240, 159]
+        }
+        .to_string(),
+        "incomplete utf-8 byte sequence [240, 159] at offset 3"
+    );
+}
+
+#[test]
+fn test_strict_io_write_error() {
+    use std::io::Write;
+
+    let mut stream = Utf8Stream::with_policy(InvalidPolicy::Strict);
+    let error = stream.write_all(b"red\xffheart").unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(
+        error.to_string(),
+        "invalid utf-8 sequence of 1 bytes at offset 3"
+    );
+}
+
+#[test]
+fn test_strict_io_write_reports_invalid_sequence_after_valid_prefix() {
+    use std::io::Write;
+
+    let mut stream = Utf8Stream::with_policy(InvalidPolicy::Strict);
+    assert_eq!(stream.write(b"red\xffheart").unwrap(), 3);
+    assert_eq!(stream.as_str(), "red");
+    let error = stream.write(b"\xffheart").unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(stream.as_str(), "red");
+    stream.write_all(&[0xe2, 0x9d]).unwrap();
+    assert!(stream.write(b"heart").is_err());
+    assert_eq!(stream.as_str(), "red");
+    assert_eq!(stream.write(b"heart").unwrap(), 5);
+    assert_eq!(stream.as_str(), "redheart");
+}
+
+#[test]
+fn test_policy_applies_to_next_get_and_as_str() {
+    let mut stream = Utf8Stream::with_policy(InvalidPolicy::Escape);
+    stream.push_bytes(b"r\xffd").unwrap();
+    assert_eq!(stream.get(1), Some("\\"));
+    assert_eq!(
+        stream.collect::<Vec<String>>(),
+        vec!["r", "\\", "x", "f", "f", "d"]
+    );
+}
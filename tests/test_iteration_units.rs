@@ -0,0 +1,89 @@
+use utf8_stream::Utf8Stream;
+
+#[test]
+fn test_bytes() {
+    let stream = Utf8Stream::new("d❤️h");
+    assert_eq!(
+        stream.bytes().collect::<Vec<u8>>(),
+        vec![100, 226, 157, 164, 239, 184, 143, 104]
+    );
+    assert_eq!(stream.bytes().len(), 8);
+    assert_eq!(stream.bytes().next_back(), Some(104));
+}
+
+#[test]
+fn test_chars() {
+    let stream = Utf8Stream::new("d❤️h");
+    assert_eq!(
+        stream.chars().collect::<Vec<char>>(),
+        vec!['d', '❤', '\u{fe0f}', 'h']
+    );
+    assert_eq!(
+        stream.chars().rev().collect::<Vec<char>>(),
+        vec!['h', '\u{fe0f}', '❤', 'd']
+    );
+}
+
+#[test]
+fn test_graphemes() {
+    let stream = Utf8Stream::new("fire👩🏽‍🚒e\u{301}");
+    assert_eq!(
+        stream.graphemes().collect::<Vec<&str>>(),
+        vec!["f", "i", "r", "e", "👩🏽‍🚒", "e\u{301}"]
+    );
+    assert_eq!(
+        stream.graphemes().rev().collect::<Vec<&str>>(),
+        vec!["e\u{301}", "👩🏽‍🚒", "e", "r", "i", "f"]
+    );
+}
+
+#[test]
+fn test_words() {
+    let stream = Utf8Stream::new("red ❤️ heart, can't stop!");
+    assert_eq!(
+        stream.words().collect::<Vec<&str>>(),
+        vec!["red", "heart", "can't", "stop"]
+    );
+}
+
+#[test]
+fn test_sentences() {
+    let stream = Utf8Stream::new("Red heart. Fire fighter! Done?");
+    assert_eq!(
+        stream.sentences().collect::<Vec<&str>>(),
+        vec!["Red heart. ", "Fire fighter! ", "Done?"]
+    );
+}
+
+#[test]
+fn test_lines() {
+    let stream = Utf8Stream::new("red\n❤️\r\nheart\n");
+    assert_eq!(
+        stream.lines().collect::<Vec<&str>>(),
+        vec!["red", "❤️", "heart"]
+    );
+}
+
+#[test]
+fn test_units_start_at_cursor() {
+    let mut stream = Utf8Stream::new("red❤️ heart\nfire👩🏽‍🚒fighter");
+    assert_eq!(stream.next().as_deref(), Some("r"));
+    assert_eq!(stream.next().as_deref(), Some("e"));
+    assert_eq!(stream.next().as_deref(), Some("d"));
+
+    assert_eq!(stream.bytes().next(), Some(226));
+    assert_eq!(stream.chars().next(), Some('❤'));
+    assert_eq!(stream.graphemes().next(), Some("❤️"));
+    assert_eq!(
+        stream.words().collect::<Vec<&str>>(),
+        vec!["heart", "fire", "fighter"]
+    );
+    assert_eq!(
+        stream.lines().collect::<Vec<&str>>(),
+        vec!["❤️ heart", "fire👩🏽‍🚒fighter"]
+    );
+
+    assert_eq!(stream.next().as_deref(), Some("❤️"));
+    stream.rewind();
+    assert_eq!(stream.chars().next(), Some('r'));
+}
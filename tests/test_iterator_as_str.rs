@@ -5,8 +5,11 @@ fn test_utf8_esoteric() {
     let mut stream = Utf8Stream::new("👩🏽‍🚒");
     assert_eq!(stream.as_str(), "👩🏽‍🚒");
     assert_eq!(stream.get(0), Some("👩🏽‍🚒"));
-    assert_eq!(stream.next(), Some("👩🏽‍🚒"));
-    assert_eq!(stream.next_back(), Some("👩🏽‍🚒"));
+    assert_eq!(stream.next().as_deref(), Some("👩🏽‍🚒"));
+    assert_eq!(stream.next_back(), None);
+    stream.rewind();
+    assert_eq!(stream.next_back().as_deref(), Some("👩🏽‍🚒"));
+    assert_eq!(stream.next(), None);
 }
 
 #[test]
@@ -14,24 +17,20 @@ fn test_iter_new() {
     let mut stream = Utf8Stream::new("test");
     assert_eq!(stream.as_str(), "test");
     assert_eq!(stream.as_bytes(), b"test");
-    assert_eq!(stream.next(), Some("t"));
-    assert_eq!(stream.next(), Some("e"));
-    assert_eq!(stream.next_back(), Some("e"));
-    assert_eq!(stream.next_back(), Some("t"));
-    assert_eq!(
-        stream.map(String::from).collect::<Vec<String>>(),
-        vec!["t", "e", "s", "t"]
-    );
+    assert_eq!(stream.next().as_deref(), Some("t"));
+    assert_eq!(stream.next().as_deref(), Some("e"));
+    assert_eq!(stream.next_back().as_deref(), Some("t"));
+    assert_eq!(stream.next_back().as_deref(), Some("s"));
+    assert_eq!(stream.next_back(), None);
+    stream.rewind();
+    assert_eq!(stream.collect::<Vec<String>>(), vec!["t", "e", "s", "t"]);
 }
 
 #[test]
 fn test_utf8_heart() {
     let stream = Utf8Stream::new("❤️");
     assert_eq!(stream.as_str(), "❤️");
-    assert_eq!(
-        stream.map(String::from).collect::<Vec<String>>(),
-        vec!["❤️"]
-    );
+    assert_eq!(stream.collect::<Vec<String>>(), vec!["❤️"]);
 }
 
 #[test]
@@ -39,7 +38,7 @@ fn test_utf8_redheart() {
     let stream = Utf8Stream::new("red❤️heart");
     assert_eq!(stream.as_str(), "red❤️heart");
     assert_eq!(
-        stream.map(String::from).collect::<Vec<String>>(),
+        stream.collect::<Vec<String>>(),
         vec!["r", "e", "d", "❤️", "h", "e", "a", "r", "t"]
     );
 }
@@ -49,7 +48,7 @@ fn test_utf8_fire_fighter() {
     let stream = Utf8Stream::new("fire👩🏽‍🚒fighter");
     assert_eq!(stream.as_str(), "fire👩🏽‍🚒fighter");
     assert_eq!(
-        stream.map(String::from).collect::<Vec<String>>(),
+        stream.collect::<Vec<String>>(),
         vec!["f", "i", "r", "e", "👩🏽‍🚒", "f", "i", "g", "h", "t", "e", "r"]
     );
 }
@@ -0,0 +1,163 @@
+use proptest::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+use utf8_stream::{Match, Matcher, Utf8Stream};
+
+mod common;
+use common::{SEARCH_PIECES, text};
+
+fn matches(matcher: &mut Matcher, stream: &Utf8Stream) -> Vec<(usize, usize, usize)> {
+    std::iter::from_fn(|| matcher.find(stream))
+        .map(|found| (found.pattern(), found.start(), found.end()))
+        .collect()
+}
+
+fn finish(matcher: &mut Matcher, stream: &Utf8Stream) -> Vec<(usize, usize, usize)> {
+    std::iter::from_fn(|| matcher.finish(stream))
+        .map(|found| (found.pattern(), found.start(), found.end()))
+        .collect()
+}
+
+#[test]
+fn test_matcher_finds_overlapping_matches() {
+    let mut matcher = Matcher::new(["he", "she", "his", "hers"]);
+    let stream = Utf8Stream::new("ushers");
+    assert_eq!(matches(&mut matcher, &stream), vec![(1, 1, 4), (0, 2, 4)]);
+    assert_eq!(finish(&mut matcher, &stream), vec![(3, 2, 6)]);
+    assert_eq!(matcher.finish(&stream), None);
+    assert_eq!(matcher.offset(), 6);
+}
+
+#[test]
+fn test_matcher_finds_matches_across_pushes() {
+    let mut matcher = Matcher::new(["heart", "fire"]);
+    let mut stream = Utf8Stream::new("");
+    let mut found = Vec::new();
+    for chunk in ["red he", "a", "rt fi", "re🔥 he", "art"] {
+        stream.push(chunk);
+        found.extend(matches(&mut matcher, &stream));
+    }
+    found.extend(finish(&mut matcher, &stream));
+    assert_eq!(found, vec![(0, 4, 9), (1, 10, 14), (0, 19, 24)]);
+}
+
+#[test]
+fn test_matcher_reports_cluster_offsets() {
+    let mut matcher = Matcher::new(["fighter"]);
+    let stream = Utf8Stream::new("👩🏽‍🚒fighter!");
+    let found = matcher.find(&stream).unwrap();
+    assert_eq!(found.range(), 15..22);
+    assert_eq!((found.cluster_start(), found.cluster_end()), (1, 8));
+}
+
+#[test]
+fn test_matcher_holds_matches_in_last_cluster() {
+    let mut matcher = Matcher::new(["❤"]);
+    let mut stream = Utf8Stream::new("red ❤");
+    assert_eq!(matcher.find(&stream), None);
+
+    stream.push("\u{fe0f}");
+    assert_eq!(matcher.find(&stream), None);
+    assert_eq!(matcher.finish(&stream), None);
+
+    let mut matcher = Matcher::new(["❤"]);
+    let stream = Utf8Stream::new("red ❤");
+    assert_eq!(matcher.find(&stream), None);
+    let found: Option<Match> = matcher.finish(&stream);
+    assert_eq!(found.map(|found| found.range()), Some(4..7));
+}
+
+#[test]
+fn test_matcher_patterns() {
+    let mut matcher = Matcher::new(vec![String::from("a"), String::new(), String::from("a")]);
+    assert_eq!(matcher.patterns(), ["a", "", "a"]);
+    let stream = Utf8Stream::new("ab");
+    assert_eq!(matches(&mut matcher, &stream), vec![(0, 0, 1), (2, 0, 1)]);
+}
+
+#[test]
+fn test_matcher_keeps_its_place_as_consumed_data_is_reclaimed() {
+    let mut matcher = Matcher::new(["ab"]);
+    let mut stream = Utf8Stream::with_capacity_bounded(8);
+    let mut found = Vec::new();
+    for chunk in ["xxab.", "yab.", "zzzab"] {
+        stream.try_push(chunk).unwrap();
+        found.extend(matches(&mut matcher, &stream));
+        stream.by_ref().for_each(drop);
+    }
+    found.extend(finish(&mut matcher, &stream));
+    assert_eq!(found, vec![(0, 2, 4), (0, 1, 3), (0, 3, 5)]);
+    assert_eq!(matcher.offset(), 14);
+}
+
+#[test]
+fn test_matcher_scans_again_after_cut() {
+    let mut matcher = Matcher::new(["heart", "art"]);
+    let mut stream = Utf8Stream::new("a heart!");
+    assert_eq!(matches(&mut matcher, &stream), vec![(0, 2, 7), (1, 4, 7)]);
+    stream.truncate(4);
+    stream.push("art?");
+    assert_eq!(matches(&mut matcher, &stream), vec![(0, 2, 7), (1, 4, 7)]);
+    assert_eq!(stream.pop().as_deref(), Some("?"));
+    assert_eq!(matches(&mut matcher, &stream), vec![]);
+    assert_eq!(stream.pop().as_deref(), Some("t"));
+    stream.push("d");
+    assert_eq!(finish(&mut matcher, &stream), vec![]);
+    stream.clear();
+    stream.push("heart");
+    assert_eq!(finish(&mut matcher, &stream), vec![(0, 0, 5), (1, 2, 5)]);
+}
+
+fn naive_matches(text: &str, patterns: &[String]) -> Vec<(usize, usize, usize)> {
+    let boundaries: Vec<usize> = text
+        .grapheme_indices(true)
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(text.len()))
+        .collect();
+    let mut found = Vec::new();
+    for end in 1..=text.len() {
+        let mut ending: Vec<(usize, usize, usize)> = patterns
+            .iter()
+            .enumerate()
+            .filter(|(_, pattern)| !pattern.is_empty() && pattern.len() <= end)
+            .map(|(index, pattern)| (index, end - pattern.len(), end))
+            .filter(|&(index, start, end)| {
+                text.as_bytes()[start..end] == *patterns[index].as_bytes()
+                    && boundaries.contains(&start)
+                    && boundaries.contains(&end)
+            })
+            .collect();
+        // longer patterns first, then in pattern order
+        ending.sort_by_key(|&(index, start, _)| (start, index));
+        found.extend(ending);
+    }
+    found
+}
+
+proptest! {
+    #[test]
+    fn test_matcher_matches_naive_search(
+        text in text(SEARCH_PIECES, 16),
+        patterns in prop::collection::vec(text(SEARCH_PIECES, 16), 1..6),
+        cuts in prop::collection::vec(any::<usize>(), 0..4),
+    ) {
+        let patterns: Vec<String> = patterns
+            .into_iter()
+            .map(|pattern| pattern.chars().take(4).collect())
+            .collect();
+        let mut cuts: Vec<usize> = cuts.into_iter().map(|cut| cut % (text.len() + 1)).collect();
+        cuts.sort();
+        cuts.push(text.len());
+
+        let mut matcher = Matcher::new(&patterns);
+        let mut stream = Utf8Stream::new("");
+        let mut found = Vec::new();
+        let mut start = 0;
+        for &cut in &cuts {
+            stream.push_bytes(&text.as_bytes()[start..cut]).unwrap();
+            start = cut;
+            found.extend(matches(&mut matcher, &stream));
+        }
+        found.extend(finish(&mut matcher, &stream));
+        prop_assert_eq!(found, naive_matches(&text, &patterns));
+    }
+}
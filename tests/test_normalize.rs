@@ -0,0 +1,164 @@
+use proptest::prelude::*;
+use utf8_stream::{NormalizationForm, Normalizer, Utf8Stream, Utf8StreamError};
+
+mod common;
+use common::text;
+
+const FORMS: [NormalizationForm; 4] = [
+    NormalizationForm::Nfc,
+    NormalizationForm::Nfd,
+    NormalizationForm::Nfkc,
+    NormalizationForm::Nfkd,
+];
+
+#[test]
+fn test_normalization_forms() {
+    assert_eq!(NormalizationForm::Nfc.normalize("e\u{301}"), "\u{e9}");
+    assert_eq!(NormalizationForm::Nfd.normalize("\u{e9}"), "e\u{301}");
+    assert_eq!(NormalizationForm::Nfkc.normalize("ﬁ"), "fi");
+    assert_eq!(NormalizationForm::Nfkd.normalize("\u{e9}ﬁ"), "e\u{301}fi");
+    assert_eq!(NormalizationForm::Nfc.normalize("\u{1100}\u{1161}"), "가");
+    assert_eq!(NormalizationForm::Nfd.normalize("가"), "\u{1100}\u{1161}");
+}
+
+#[test]
+fn test_normalizer_holds_combinable_tail() {
+    let mut normalizer = Normalizer::new(NormalizationForm::Nfc);
+    assert_eq!(normalizer.push("cafe"), "caf");
+    assert_eq!(normalizer.held(), "e");
+    assert_eq!(normalizer.push("\u{301}"), "");
+    assert_eq!(normalizer.held(), "e\u{301}");
+    assert_eq!(normalizer.push(" au lait"), "\u{e9} au lai");
+    assert_eq!(normalizer.finish(), "t");
+    assert_eq!(normalizer.held(), "");
+}
+
+#[test]
+fn test_normalizer_reorders_marks_across_pushes() {
+    let mut normalizer = Normalizer::new(NormalizationForm::Nfd);
+    let mut output = normalizer.push("a\u{301}");
+    output.push_str(&normalizer.push("\u{316}"));
+    output.push_str(&normalizer.finish());
+    assert_eq!(output, "a\u{316}\u{301}");
+}
+
+#[test]
+fn test_normalizer_composes_hangul_across_pushes() {
+    let mut normalizer = Normalizer::new(NormalizationForm::Nfc);
+    let mut output = normalizer.push("\u{1100}");
+    output.push_str(&normalizer.push("\u{1161}"));
+    output.push_str(&normalizer.push("\u{11a8}"));
+    output.push_str(&normalizer.finish());
+    assert_eq!(output, "각");
+}
+
+#[test]
+fn test_stream_with_normalization() {
+    let mut stream = Utf8Stream::with_normalization(NormalizationForm::Nfc);
+    assert_eq!(stream.normalization(), Some(NormalizationForm::Nfc));
+
+    stream.push("cafe");
+    assert_eq!(stream.as_str(), "caf");
+    stream.push_bytes(&"\u{301}".as_bytes()[..1]).unwrap();
+    stream.push_bytes(&"\u{301}".as_bytes()[1..]).unwrap();
+    assert_eq!(stream.as_str(), "caf");
+    stream.finish().unwrap();
+    assert_eq!(stream.as_str(), "caf\u{e9}");
+    assert_eq!(
+        stream.collect::<Vec<String>>(),
+        vec!["c", "a", "f", "\u{e9}"]
+    );
+}
+
+#[test]
+fn test_set_normalization_flushes_held_text() {
+    let mut stream = Utf8Stream::with_normalization(NormalizationForm::Nfd);
+    stream.push("\u{e9}");
+    assert_eq!(stream.as_str(), "");
+    stream.set_normalization(None);
+    assert_eq!(stream.normalization(), None);
+    assert_eq!(stream.as_str(), "e\u{301}");
+    stream.push("\u{e9}");
+    assert_eq!(stream.as_str(), "e\u{301}\u{e9}");
+}
+
+#[test]
+fn test_clear_drops_held_text() {
+    let mut stream = Utf8Stream::with_normalization(NormalizationForm::Nfc);
+    stream.push("abc");
+    stream.clear();
+    assert_eq!(stream.normalization(), Some(NormalizationForm::Nfc));
+    stream.push("x");
+    stream.finish().unwrap();
+    assert_eq!(stream.as_str(), "x");
+}
+
+#[test]
+fn test_bounded_stream_counts_held_text() {
+    let mut stream = Utf8Stream::with_capacity_bounded(3);
+    stream.set_normalization(Some(NormalizationForm::Nfc));
+    stream.try_push("abc").unwrap();
+    assert_eq!(stream.as_str(), "ab");
+    assert_eq!(stream.remaining_capacity(), Some(0));
+    assert_eq!(
+        stream.try_push("d"),
+        Err(Utf8StreamError::CapacityExceeded {
+            capacity: 3,
+            length: 4
+        })
+    );
+    stream.finish().unwrap();
+    assert_eq!(stream.as_str(), "abc");
+
+    let mut stream = Utf8Stream::with_capacity_bounded(2);
+    stream.set_normalization(Some(NormalizationForm::Nfd));
+    assert!(stream.try_push("\u{e9}").is_err());
+    stream.try_push("e").unwrap();
+    stream.finish().unwrap();
+    assert_eq!(stream.as_str(), "e");
+}
+
+#[test]
+fn test_normalize_stream() {
+    let stream = Utf8Stream::new("cafe\u{301} ﬁ");
+    assert_eq!(
+        stream.normalize(NormalizationForm::Nfc).as_str(),
+        "caf\u{e9} ﬁ"
+    );
+    assert_eq!(
+        stream.normalize(NormalizationForm::Nfkd).as_str(),
+        "cafe\u{301} fi"
+    );
+    assert_eq!(stream.as_str(), "cafe\u{301} ﬁ");
+}
+
+/// composable letters, combining marks of different classes,
+/// compatibility characters and hangul jamo
+const NORMALIZATION_PIECES: &[&str] = &[
+    "a", "e", " ", "\u{301}", "\u{316}", "\u{e9}", "ﬁ", "\u{1100}", "\u{1161}", "\u{11a8}", "가",
+    "\u{212b}",
+];
+
+proptest! {
+    #[test]
+    fn test_chunked_normalization_matches_whole(text in text(NORMALIZATION_PIECES, 24), cuts in prop::collection::vec(any::<usize>(), 0..6)) {
+        let mut cuts: Vec<usize> = cuts
+            .into_iter()
+            .map(|cut| cut % (text.len() + 1))
+            .filter(|&cut| text.is_char_boundary(cut))
+            .collect();
+        cuts.sort();
+        cuts.push(text.len());
+
+        for form in FORMS {
+            let mut stream = Utf8Stream::with_normalization(form);
+            let mut start = 0;
+            for &cut in &cuts {
+                stream.push_bytes(&text.as_bytes()[start..cut]).unwrap();
+                start = cut;
+            }
+            stream.finish().unwrap();
+            prop_assert_eq!(stream.as_str(), form.normalize(&text));
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::io::SeekFrom;
+
+use utf8_stream::{ColumnUnit, Position, Utf8Reader, Utf8Stream};
+
+fn position(line: usize, column: usize, offset: usize) -> Position {
+    Position {
+        line,
+        column,
+        offset,
+    }
+}
+
+#[test]
+fn test_line_breaks() {
+    let mut stream = Utf8Stream::new("a\nb\r\nc\rd\u{2028}e\u{2029}f");
+    let mut lines = Vec::new();
+    while let Some(cluster) = stream.next() {
+        if cluster.chars().all(char::is_alphabetic) {
+            lines.push((cluster, stream.location().line));
+        }
+    }
+    assert_eq!(
+        lines,
+        vec![
+            ("a".to_string(), 1),
+            ("b".to_string(), 2),
+            ("c".to_string(), 3),
+            ("d".to_string(), 4),
+            ("e".to_string(), 5),
+            ("f".to_string(), 6),
+        ]
+    );
+}
+
+#[test]
+fn test_crlf_split_across_pushes() {
+    let mut stream = Utf8Stream::new("red\r");
+    stream.advance_by(4).unwrap();
+    assert_eq!(stream.location(), position(2, 1, 4));
+    stream.push("\nheart");
+    assert_eq!(stream.remaining_clusters(), 6);
+    assert_eq!(stream.next().as_deref(), Some("\n"));
+    assert_eq!(stream.location(), position(2, 1, 5));
+    stream.next();
+    assert_eq!(stream.location(), position(2, 2, 6));
+}
+
+#[test]
+fn test_column_units() {
+    let mut stream = Utf8Stream::new("e\u{301}漢👩🏽‍🚒\tx");
+    stream.advance_by(4).unwrap();
+    assert_eq!(stream.column_unit(), ColumnUnit::Graphemes);
+    assert_eq!(stream.location().column, 5);
+    stream.set_column_unit(ColumnUnit::Chars);
+    assert_eq!(stream.location().column, 9);
+    stream.set_column_unit(ColumnUnit::Cells);
+    assert_eq!(stream.tab_width(), 8);
+    assert_eq!(stream.location().column, 9);
+    stream.set_tab_width(2);
+    assert_eq!(stream.location().column, 7);
+    stream.next();
+    assert_eq!(stream.location().column, 8);
+}
+
+#[test]
+fn test_location_follows_seek_rewind_and_pop() {
+    let mut stream = Utf8Stream::new("red\n❤️heart");
+    stream.seek(SeekFrom::Start(7)).unwrap();
+    assert_eq!(stream.location(), position(2, 1, 4));
+    stream.seek(SeekFrom::End(0)).unwrap();
+    assert_eq!(stream.location(), position(2, 7, 15));
+    stream.pop();
+    assert_eq!(stream.location(), position(2, 6, 14));
+    stream.truncate(4);
+    assert_eq!(stream.location(), position(2, 1, 4));
+    stream.rewind();
+    assert_eq!(stream.location(), Position::default());
+    stream.clear();
+    assert_eq!(stream.location(), position(1, 1, 0));
+}
+
+#[test]
+fn test_location_survives_reclaim() {
+    let mut stream = Utf8Stream::with_capacity_bounded(4);
+    for chunk in ["re", "d\n", "❤", "he", "ar", "t"] {
+        if stream.try_push(chunk).is_err() {
+            stream.advance_by(stream.remaining_clusters()).unwrap();
+            stream.try_push(chunk).unwrap();
+        }
+    }
+    stream.advance_by(stream.remaining_clusters()).unwrap();
+    assert_eq!(stream.location(), position(2, 7, 12));
+    stream.rewind();
+    assert_eq!(stream.location(), position(2, 6, 11));
+}
+
+#[test]
+fn test_reader_tracks_location() {
+    let mut reader = Utf8Reader::with_chunk_size(1, "fire\r\n👩🏽‍🚒".as_bytes());
+    for _ in 0..5 {
+        reader.next().unwrap().unwrap();
+    }
+    assert_eq!(reader.stream().location(), position(2, 1, 6));
+}
+
+#[test]
+fn test_position_display() {
+    assert_eq!(position(3, 14, 40).to_string(), "3:14");
+}
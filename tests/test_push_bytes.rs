@@ -0,0 +1,68 @@
+use utf8_stream::Utf8Stream;
+
+#[test]
+fn test_push_bytes_ascii() {
+    let mut stream = Utf8Stream::default();
+    stream.push_bytes(b"test").unwrap();
+    assert_eq!(stream.as_str(), "test");
+    assert_eq!(stream.pending(), &[]);
+}
+
+#[test]
+fn test_push_bytes_split_multi_byte_sequence() {
+    let mut stream = Utf8Stream::new("red");
+    stream.push_bytes(&[226, 157]).unwrap();
+    assert_eq!(stream.as_str(), "red");
+    assert_eq!(stream.len(), 3);
+    assert_eq!(stream.pending(), &[226, 157]);
+
+    stream.push_bytes(&[164, 239]).unwrap();
+    assert_eq!(stream.as_str(), "red❤");
+    assert_eq!(stream.pending(), &[239]);
+
+    stream.push_bytes(&[184, 143]).unwrap();
+    stream.push_bytes(b"heart").unwrap();
+    assert_eq!(stream.as_str(), "red❤️heart");
+    assert_eq!(stream.pending(), &[]);
+    assert_eq!(
+        stream.collect::<Vec<String>>(),
+        vec!["r", "e", "d", "❤️", "h", "e", "a", "r", "t"]
+    );
+}
+
+#[test]
+fn test_push_bytes_one_byte_at_a_time() {
+    let mut stream = Utf8Stream::default();
+    for byte in "fire👩🏽‍🚒fighter".bytes() {
+        stream.push_bytes(&[byte]).unwrap();
+    }
+    assert_eq!(stream.as_str(), "fire👩🏽‍🚒fighter");
+    assert_eq!(stream.len(), 26);
+}
+
+#[test]
+fn test_push_bytes_replaces_invalid_sequences() {
+    let mut stream = Utf8Stream::default();
+    stream.push_bytes(b"a\xffb\xe2\x9dc").unwrap();
+    assert_eq!(stream.as_str(), "a\u{fffd}b\u{fffd}c");
+    assert_eq!(stream.pending(), &[]);
+}
+
+#[test]
+fn test_finish_replaces_incomplete_tail() {
+    let mut stream = Utf8Stream::new("red");
+    stream.push_bytes(&[240, 159]).unwrap();
+    assert_eq!(stream.as_str(), "red");
+    stream.finish().unwrap();
+    assert_eq!(stream.as_str(), "red\u{fffd}");
+    assert_eq!(stream.pending(), &[]);
+}
+
+#[test]
+fn test_extend_bytes_with_split_sequence() {
+    let mut stream = Utf8Stream::default();
+    stream.extend([114, 101, 100, 226, 157]);
+    assert_eq!(stream.as_str(), "red");
+    stream.extend([164, 239, 184, 143]);
+    assert_eq!(stream.as_str(), "red❤️");
+}
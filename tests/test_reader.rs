@@ -0,0 +1,86 @@
+use std::io::{Cursor, Read};
+
+use utf8_stream::{Utf8Reader, Utf8Stream};
+
+struct OneByteAtATime<'a> {
+    bytes: &'a [u8],
+}
+
+impl Read for OneByteAtATime<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self.bytes.split_first() {
+            Some((byte, rest)) if !buf.is_empty() => {
+                buf[0] = *byte;
+                self.bytes = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+struct FailingReader;
+
+impl Read for FailingReader {
+    fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("broken pipe"))
+    }
+}
+
+#[test]
+fn test_from_reader() {
+    let reader = Utf8Stream::from_reader(Cursor::new("fire👩🏽‍🚒fighter"));
+    assert_eq!(
+        reader.map(Result::unwrap).collect::<Vec<String>>(),
+        vec!["f", "i", "r", "e", "👩🏽‍🚒", "f", "i", "g", "h", "t", "e", "r"]
+    );
+}
+
+#[test]
+fn test_reader_with_split_sequences() {
+    let reader = Utf8Stream::from_reader(OneByteAtATime {
+        bytes: "red❤️heart".as_bytes(),
+    });
+    assert_eq!(
+        reader.map(Result::unwrap).collect::<Vec<String>>(),
+        vec!["r", "e", "d", "❤️", "h", "e", "a", "r", "t"]
+    );
+}
+
+#[test]
+fn test_reader_holds_back_clusters_across_chunks() {
+    let reader = Utf8Reader::with_chunk_size(4, "cafe\u{301}s 🇧🇷🇵🇹".as_bytes());
+    assert_eq!(
+        reader.map(Result::unwrap).collect::<Vec<String>>(),
+        vec!["c", "a", "f", "e\u{301}", "s", " ", "🇧🇷", "🇵🇹"]
+    );
+}
+
+#[test]
+fn test_reader_memory_stays_bounded() {
+    let input = "❤️".repeat(10_000);
+    let mut reader = Utf8Reader::with_chunk_size(64, input.as_bytes());
+    let mut count = 0;
+    while let Some(cluster) = reader.next() {
+        assert_eq!(cluster.unwrap(), "❤️");
+        assert!(reader.stream().len() < 128);
+        count += 1;
+    }
+    assert_eq!(count, 10_000);
+}
+
+#[test]
+fn test_reader_replaces_incomplete_tail_at_eof() {
+    let reader = Utf8Stream::from_reader(&[114, 101, 100, 226, 157][..]);
+    assert_eq!(
+        reader.map(Result::unwrap).collect::<Vec<String>>(),
+        vec!["r", "e", "d", "\u{fffd}"]
+    );
+}
+
+#[test]
+fn test_reader_error() {
+    let mut reader = Utf8Stream::from_reader(FailingReader);
+    let error = reader.next().unwrap().unwrap_err();
+    assert_eq!(error.to_string(), "broken pipe");
+}
@@ -0,0 +1,124 @@
+use utf8_stream::{Utf8Stream, Utf8StreamError};
+
+fn records(stream: &mut Utf8Stream, delimiter: &str) -> Vec<String> {
+    stream
+        .records(delimiter)
+        .collect::<Result<Vec<String>, Utf8StreamError>>()
+        .unwrap()
+}
+
+#[test]
+fn test_newline_delimited_records() {
+    let mut stream = Utf8Stream::new("red\n❤️\n\nhe");
+    assert_eq!(records(&mut stream, "\n"), vec!["red", "❤️", ""]);
+    assert_eq!(stream.as_str(), "he");
+    assert_eq!(records(&mut stream, "\n"), Vec::<String>::new());
+
+    stream.push("art");
+    assert_eq!(records(&mut stream, "\n"), Vec::<String>::new());
+    stream.push("\nfire");
+    assert_eq!(records(&mut stream, "\n"), vec!["heart"]);
+    assert_eq!(stream.as_str(), "fire");
+}
+
+#[test]
+fn test_nul_delimited_records() {
+    let mut stream = Utf8Stream::new("");
+    stream.push_bytes(b"red\0\xe2\x9d").unwrap();
+    assert_eq!(records(&mut stream, "\0"), vec!["red"]);
+    stream.push_bytes(b"\xa4\xef\xb8\x8f\0").unwrap();
+    assert_eq!(records(&mut stream, "\0"), vec!["❤️"]);
+    assert!(stream.is_empty());
+}
+
+#[test]
+fn test_multi_byte_delimiter_across_pushes() {
+    let mut stream = Utf8Stream::new("red\r");
+    assert_eq!(records(&mut stream, "\r\n"), Vec::<String>::new());
+    stream.push("\nheart\r\n");
+    assert_eq!(records(&mut stream, "\r\n"), vec!["red", "heart"]);
+}
+
+#[test]
+fn test_records_remainder() {
+    let mut stream = Utf8Stream::new("red\nhe");
+    let mut records = stream.records("\n");
+    assert_eq!(records.remainder(), "red\nhe");
+    assert_eq!(records.next(), Some(Ok("red".to_string())));
+    assert_eq!(records.remainder(), "he");
+    assert_eq!(records.next(), None);
+}
+
+#[test]
+fn test_records_start_at_cursor() {
+    let mut stream = Utf8Stream::new("❤️red\nheart\n");
+    assert_eq!(stream.next(), Some("❤️".to_string()));
+    assert_eq!(records(&mut stream, "\n"), vec!["red", "heart"]);
+    assert!(stream.is_empty());
+}
+
+#[test]
+fn test_records_keep_clusters_and_position_in_sync() {
+    let mut stream = Utf8Stream::new("red\r\nheart\r\nfi");
+    assert_eq!(records(&mut stream, "\n"), vec!["red\r", "heart\r"]);
+    assert_eq!(stream.len(), 2);
+    assert_eq!(stream.cluster_count(), 2);
+    assert_eq!(stream.location().line, 3);
+    assert_eq!(stream.location().column, 1);
+
+    stream.push("re");
+    assert_eq!(stream.collect::<Vec<String>>(), vec!["f", "i", "r", "e"]);
+}
+
+#[test]
+fn test_records_max_length() {
+    let mut stream = Utf8Stream::new("red\nfirefighter\nheart\n");
+    let mut records = stream.records("\n").max_length(5);
+    assert_eq!(records.next(), Some(Ok("red".to_string())));
+    assert_eq!(
+        records.next(),
+        Some(Err(Utf8StreamError::RecordTooLong {
+            limit: 5,
+            length: 11
+        }))
+    );
+    assert_eq!(records.next(), Some(Ok("heart".to_string())));
+    assert_eq!(records.next(), None);
+}
+
+#[test]
+fn test_records_max_length_discards_across_pushes() {
+    let mut stream = Utf8Stream::new("red\nfire");
+    let mut framed = stream.records("\r\n").max_length(4);
+    assert_eq!(
+        framed.next(),
+        Some(Err(Utf8StreamError::RecordTooLong {
+            limit: 4,
+            length: 7
+        }))
+    );
+    assert_eq!(framed.next(), None);
+    drop(framed);
+    assert_eq!(stream.as_str(), "e");
+
+    stream.push("fighter\r");
+    assert_eq!(records(&mut stream, "\r\n"), Vec::<String>::new());
+    assert_eq!(stream.as_str(), "\r");
+    stream.push("\nheart\r\n");
+    assert_eq!(records(&mut stream, "\r\n"), vec!["heart"]);
+}
+
+#[test]
+fn test_records_with_empty_delimiter() {
+    let mut stream = Utf8Stream::new("red");
+    assert_eq!(stream.records("").next(), None);
+    assert_eq!(stream.as_str(), "red");
+}
+
+#[test]
+fn test_records_of_borrowed_stream() {
+    let input = String::from("red\n❤️\n");
+    let mut stream = Utf8Stream::borrowed(&input);
+    assert_eq!(records(&mut stream, "\n"), vec!["red", "❤️"]);
+    assert!(stream.is_borrowed());
+}
@@ -0,0 +1,175 @@
+use regex::Regex;
+use utf8_stream::{RegexFinder, Utf8Stream};
+
+fn found<'a>(iter: impl Iterator<Item = regex::Match<'a>>) -> Vec<(usize, &'a str)> {
+    iter.map(|found| (found.start(), found.as_str())).collect()
+}
+
+#[test]
+fn test_regex_find_iter() {
+    let stream = Utf8Stream::new("red❤️heart❤");
+    let regex = Regex::new("❤|[a-z]+").unwrap();
+    assert_eq!(
+        found(stream.regex_find_iter(&regex)),
+        vec![(0, "red"), (9, "heart"), (14, "❤")]
+    );
+}
+
+#[test]
+fn test_regex_find_iter_skips_matches_within_clusters() {
+    let stream = Utf8Stream::new("cafe\u{301} e👩🏽‍🚒");
+    let regex = Regex::new(r"e|\p{Emoji_Modifier}|🚒").unwrap();
+    assert_eq!(found(stream.regex_find_iter(&regex)), vec![(7, "e")]);
+
+    let regex = Regex::new(r"\w+").unwrap();
+    assert_eq!(
+        found(stream.regex_find_iter(&regex)),
+        vec![(0, "cafe\u{301}"), (7, "e")]
+    );
+}
+
+#[test]
+fn test_regex_find_iter_empty_matches() {
+    let stream = Utf8Stream::new("e\u{301}a");
+    let regex = Regex::new("").unwrap();
+    assert_eq!(
+        found(stream.regex_find_iter(&regex)),
+        vec![(0, ""), (3, ""), (4, "")]
+    );
+}
+
+#[test]
+fn test_captures_iter() {
+    let stream = Utf8Stream::new("red=❤️ fire=🔥 blue");
+    let regex = Regex::new(r"(?<key>\w+)=(?<value>\S+)").unwrap();
+    let pairs: Vec<(&str, &str)> = stream
+        .captures_iter(&regex)
+        .map(|captures| {
+            (
+                captures.name("key").unwrap().as_str(),
+                captures.name("value").unwrap().as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(pairs, vec![("red", "❤️"), ("fire", "🔥")]);
+
+    let regex = Regex::new(r"(❤)").unwrap();
+    assert_eq!(stream.captures_iter(&regex).count(), 0);
+}
+
+#[test]
+fn test_regex_finder_resumes_after_push() {
+    let mut finder = RegexFinder::new(Regex::new(r"\w+").unwrap());
+    assert_eq!(finder.regex().as_str(), r"\w+");
+    let mut stream = Utf8Stream::new("red he");
+    assert_eq!(
+        found(std::iter::from_fn(|| finder.find(&stream))),
+        vec![(0, "red")]
+    );
+    assert_eq!(finder.offset(), 3);
+
+    stream.push("art fi");
+    assert_eq!(
+        found(std::iter::from_fn(|| finder.find(&stream))),
+        vec![(4, "heart")]
+    );
+
+    stream.push("re");
+    assert_eq!(found(std::iter::from_fn(|| finder.find(&stream))), vec![]);
+    assert_eq!(
+        found(std::iter::from_fn(|| finder.finish(&stream))),
+        vec![(10, "fire")]
+    );
+}
+
+#[test]
+fn test_regex_finder_holds_matches_in_last_cluster() {
+    let mut finder = RegexFinder::new(Regex::new("❤").unwrap());
+    let mut stream = Utf8Stream::new("red❤");
+    assert_eq!(finder.find(&stream), None);
+
+    stream.push("\u{fe0f}❤ ");
+    assert_eq!(
+        found(std::iter::from_fn(|| finder.find(&stream))),
+        vec![(9, "❤")]
+    );
+}
+
+#[test]
+fn test_regex_finder_bounds_rescan_with_max_match_length() {
+    let mut finder = RegexFinder::new(Regex::new(r"❤️\d+").unwrap());
+    let mut stream = Utf8Stream::new("red ");
+    assert_eq!(finder.find(&stream), None);
+    assert_eq!(finder.offset(), 0);
+
+    let mut finder = finder.max_match_length(8);
+    for _ in 0..16 {
+        stream.push("heart ");
+        assert_eq!(finder.find(&stream), None);
+        assert_eq!(finder.offset(), stream.len() - 7);
+    }
+    stream.push("❤");
+    assert_eq!(finder.find(&stream), None);
+    stream.push("\u{fe0f}2");
+    assert_eq!(finder.find(&stream), None);
+    stream.push("6 ");
+    assert_eq!(
+        found(std::iter::from_fn(|| finder.find(&stream))),
+        vec![(100, "❤️26")]
+    );
+}
+
+#[test]
+fn test_regex_finder_keeps_its_place_as_consumed_data_is_reclaimed() {
+    let mut finder = RegexFinder::new(Regex::new("ab").unwrap());
+    let mut stream = Utf8Stream::with_capacity_bounded(8);
+    let mut starts = Vec::new();
+    for chunk in ["xxab.", "yab.", "zzzab"] {
+        stream.try_push(chunk).unwrap();
+        starts.extend(std::iter::from_fn(|| {
+            finder.find(&stream).map(|found| found.start())
+        }));
+        stream.by_ref().for_each(drop);
+    }
+    starts.extend(std::iter::from_fn(|| {
+        finder.finish(&stream).map(|found| found.start())
+    }));
+    assert_eq!(starts, vec![2, 1, 3]);
+    assert_eq!(finder.offset(), 14);
+}
+
+#[test]
+fn test_regex_finder_searches_again_after_cut() {
+    let mut finder = RegexFinder::new(Regex::new(r"\w+").unwrap());
+    let mut stream = Utf8Stream::new("red heart ");
+    assert_eq!(
+        found(std::iter::from_fn(|| finder.find(&stream))),
+        vec![(0, "red"), (4, "heart")]
+    );
+    stream.truncate(7);
+    stream.push("d ");
+    assert_eq!(
+        found(std::iter::from_fn(|| finder.find(&stream))),
+        vec![(4, "head")]
+    );
+
+    let mut finder = RegexFinder::new(Regex::new(r"\d{4}").unwrap()).max_match_length(4);
+    let mut stream = Utf8Stream::new("2026 ");
+    assert_eq!(
+        found(std::iter::from_fn(|| finder.find(&stream))),
+        vec![(0, "2026")]
+    );
+    assert_eq!(stream.pop().as_deref(), Some(" "));
+    assert_eq!(stream.pop().as_deref(), Some("6"));
+    stream.push("7 ");
+    assert_eq!(
+        found(std::iter::from_fn(|| finder.find(&stream))),
+        vec![(0, "2027")]
+    );
+    stream.clear();
+    stream.push("1999");
+    assert_eq!(
+        found(std::iter::from_fn(|| finder.finish(&stream))),
+        vec![(0, "1999")]
+    );
+}
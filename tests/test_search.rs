@@ -0,0 +1,220 @@
+use proptest::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+use utf8_stream::{Finder, Utf8Stream};
+
+mod common;
+use common::{SEARCH_PIECES, text};
+
+#[test]
+fn test_find_and_rfind() {
+    let stream = Utf8Stream::new("red❤️heart❤red");
+    assert_eq!(stream.find("red"), Some(0));
+    assert_eq!(stream.rfind("red"), Some(17));
+    assert_eq!(stream.find("❤"), Some(14));
+    assert_eq!(stream.rfind("❤"), Some(14));
+    assert_eq!(stream.find("❤️"), Some(3));
+    assert_eq!(stream.rfind("❤️"), Some(3));
+    assert_eq!(stream.find("\u{fe0f}"), None);
+    assert_eq!(stream.find("blue"), None);
+    assert_eq!(stream.find(""), Some(0));
+    assert_eq!(stream.rfind(""), Some(20));
+}
+
+#[test]
+fn test_find_ignores_cursors() {
+    let mut stream = Utf8Stream::new("red❤️heart");
+    stream.next();
+    stream.next_back();
+    assert_eq!(stream.find("red"), Some(0));
+    assert_eq!(stream.rfind("t"), Some(13));
+}
+
+#[test]
+fn test_find_never_splits_clusters() {
+    let stream = Utf8Stream::new("👩🏽‍🚒e\u{301}🇧🇷🇵🇹");
+    assert_eq!(stream.find("👩"), None);
+    assert_eq!(stream.find("🚒"), None);
+    assert_eq!(stream.find("e"), None);
+    assert_eq!(stream.find("🇷🇵"), None);
+    assert_eq!(stream.find("🇵🇹"), Some(26));
+    assert_eq!(stream.find("e\u{301}"), Some(15));
+}
+
+#[test]
+fn test_find_iter_and_match_indices() {
+    let stream = Utf8Stream::new("❤️❤❤️❤");
+    assert_eq!(stream.find_iter("❤").collect::<Vec<usize>>(), vec![6, 15]);
+    assert_eq!(
+        stream.match_indices("❤️").collect::<Vec<_>>(),
+        vec![(0, "❤️"), (9, "❤️")]
+    );
+    assert_eq!(
+        Utf8Stream::new("aaaa")
+            .find_iter("aa")
+            .collect::<Vec<usize>>(),
+        vec![0, 2]
+    );
+    assert_eq!(
+        Utf8Stream::new("e\u{301}a")
+            .find_iter("")
+            .collect::<Vec<usize>>(),
+        vec![0, 3, 4]
+    );
+}
+
+#[test]
+fn test_split() {
+    let stream = Utf8Stream::new("red❤️heart❤fire❤");
+    assert_eq!(
+        stream.split("❤").collect::<Vec<&str>>(),
+        vec!["red❤️heart", "fire", ""]
+    );
+    assert_eq!(
+        stream.split_terminator("❤").collect::<Vec<&str>>(),
+        vec!["red❤️heart", "fire"]
+    );
+    assert_eq!(
+        stream.splitn(2, "❤").collect::<Vec<&str>>(),
+        vec!["red❤️heart", "fire❤"]
+    );
+    assert_eq!(
+        stream.splitn(1, "❤").collect::<Vec<&str>>(),
+        vec![stream.as_str()]
+    );
+    assert_eq!(stream.splitn(0, "❤").count(), 0);
+    assert_eq!(
+        Utf8Stream::new("e\u{301}a")
+            .split("")
+            .collect::<Vec<&str>>(),
+        vec!["", "e\u{301}", "a", ""]
+    );
+    assert_eq!(
+        Utf8Stream::new("").split(",").collect::<Vec<&str>>(),
+        vec![""]
+    );
+    assert_eq!(Utf8Stream::new("").split_terminator(",").count(), 0);
+
+    let mut split = stream.split("❤");
+    split.next();
+    assert_eq!(split.remainder(), Some("fire❤"));
+}
+
+#[test]
+fn test_finder_searches_incrementally() {
+    let mut stream = Utf8Stream::new("red ❤");
+    let mut finder = Finder::new("❤");
+    assert_eq!(finder.pattern(), "❤");
+    assert_eq!(finder.find(&stream), None);
+
+    stream.push("\u{fe0f} ❤ he");
+    assert_eq!(finder.find(&stream), Some(11));
+    assert_eq!(finder.offset(), 14);
+    assert_eq!(finder.find(&stream), None);
+
+    stream.push("art ❤");
+    assert_eq!(finder.find(&stream), None);
+    assert_eq!(finder.finish(&stream), Some(21));
+    assert_eq!(finder.finish(&stream), None);
+}
+
+#[test]
+fn test_finder_matches_across_pushes() {
+    let mut stream = Utf8Stream::new("");
+    let mut finder = Finder::new("heart");
+    let mut matches = Vec::new();
+    for chunk in ["red he", "ar", "t blue hea", "rt!"] {
+        stream.push(chunk);
+        matches.extend(std::iter::from_fn(|| finder.find(&stream)));
+    }
+    matches.extend(std::iter::from_fn(|| finder.finish(&stream)));
+    assert_eq!(matches, vec![4, 15]);
+}
+
+#[test]
+fn test_finder_keeps_its_place_as_consumed_data_is_reclaimed() {
+    let mut stream = Utf8Stream::with_capacity_bounded(8);
+    let mut finder = Finder::new("ab");
+    let mut matches = Vec::new();
+    for chunk in ["xxab.", "yab.", "zzzab"] {
+        stream.try_push(chunk).unwrap();
+        matches.extend(std::iter::from_fn(|| finder.find(&stream)));
+        stream.by_ref().for_each(drop);
+    }
+    matches.extend(std::iter::from_fn(|| finder.finish(&stream)));
+    assert_eq!(matches, vec![2, 1, 3]);
+    assert_eq!(finder.offset(), 14);
+}
+
+#[test]
+fn test_finder_searches_again_after_cut() {
+    let mut stream = Utf8Stream::new("a heart!");
+    let mut finder = Finder::new("heart");
+    assert_eq!(finder.find(&stream), Some(2));
+    stream.truncate(4);
+    stream.push("art?");
+    assert_eq!(finder.find(&stream), Some(2));
+    assert_eq!(stream.pop().as_deref(), Some("?"));
+    assert_eq!(stream.pop().as_deref(), Some("t"));
+    stream.push("t heart");
+    assert_eq!(finder.find(&stream), Some(2));
+    assert_eq!(finder.find(&stream), None);
+    assert_eq!(finder.finish(&stream), Some(8));
+    stream.clear();
+    stream.push("heart");
+    assert_eq!(finder.finish(&stream), Some(0));
+}
+
+#[test]
+fn test_finder_does_not_overlap_match_left_by_cut() {
+    let mut stream = Utf8Stream::new("aaaaaa.");
+    let mut finder = Finder::new("aaa");
+    assert_eq!(finder.find(&stream), Some(0));
+    assert_eq!(finder.find(&stream), Some(3));
+    stream.truncate(4);
+    stream.push("aa.");
+    assert_eq!(finder.find(&stream), Some(3));
+    assert_eq!(finder.find(&stream), None);
+}
+
+fn naive_find_iter(string: &str, pattern: &str) -> Vec<usize> {
+    let boundaries: Vec<usize> = string
+        .grapheme_indices(true)
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(string.len()))
+        .collect();
+    let mut matches = Vec::new();
+    let mut from = 0;
+    for &start in &boundaries {
+        let end = start + pattern.len();
+        if start >= from && string[start..].starts_with(pattern) && boundaries.contains(&end) {
+            matches.push(start);
+            from = end.max(start + 1);
+        }
+    }
+    matches
+}
+
+proptest! {
+    #[test]
+    fn test_find_iter_matches_cluster_aligned_search(text in text(SEARCH_PIECES, 16), pattern in text(SEARCH_PIECES, 16)) {
+        let stream = Utf8Stream::new(&text);
+        let expected = naive_find_iter(&text, &pattern);
+        prop_assert_eq!(stream.find_iter(&pattern).collect::<Vec<usize>>(), expected.clone());
+        prop_assert_eq!(stream.find(&pattern), expected.first().copied());
+    }
+
+    #[test]
+    fn test_finder_matches_find_iter(text in text(SEARCH_PIECES, 16), pattern in text(SEARCH_PIECES, 16), cut in any::<usize>()) {
+        prop_assume!(!pattern.is_empty());
+        let mut cut = cut % (text.len() + 1);
+        while !text.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        let mut stream = Utf8Stream::new(&text[..cut]);
+        let mut finder = Finder::new(&pattern);
+        let mut matches: Vec<usize> = std::iter::from_fn(|| finder.find(&stream)).collect();
+        stream.push(&text[cut..]);
+        matches.extend(std::iter::from_fn(|| finder.finish(&stream)));
+        prop_assert_eq!(matches, naive_find_iter(&text, &pattern));
+    }
+}
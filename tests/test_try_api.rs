@@ -0,0 +1,143 @@
+use utf8_stream::{InvalidPolicy, Utf8Stream, Utf8StreamError};
+
+#[test]
+fn test_try_push() {
+    let mut stream = Utf8Stream::new("red");
+    assert_eq!(stream.try_push("❤️heart"), Ok(()));
+    assert_eq!(stream.as_str(), "red❤️heart");
+}
+
+#[test]
+fn test_try_push_reports_interrupted_incomplete_tail() {
+    let mut stream = Utf8Stream::with_policy(InvalidPolicy::Strict);
+    stream.push_bytes(&[114, 101, 100, 240, 159]).unwrap();
+    assert_eq!(
+        stream.try_push("heart"),
+        Err(Utf8StreamError::IncompleteTail {
+            offset: 3,
+            bytes: vec![240, 159]
+        })
+    );
+    assert_eq!(stream.as_str(), "red");
+    assert_eq!(stream.pending(), &[]);
+}
+
+#[test]
+fn test_try_get() {
+    let stream = Utf8Stream::new("red❤️heart");
+    assert_eq!(stream.try_get(0), Ok("r"));
+    assert_eq!(stream.try_get(3), Ok("❤️"));
+    assert_eq!(stream.try_get(13), Ok("t"));
+    assert_eq!(
+        stream.try_get(5),
+        Err(Utf8StreamError::NotCharBoundary { index: 5 })
+    );
+    assert_eq!(
+        stream.try_get(20),
+        Err(Utf8StreamError::IndexOutOfRange {
+            index: 20,
+            length: 14
+        })
+    );
+}
+
+#[test]
+fn test_try_pop() {
+    let mut stream = Utf8Stream::new("d❤️");
+    assert_eq!(stream.try_pop().as_deref(), Ok("❤️"));
+    assert_eq!(stream.try_pop().as_deref(), Ok("d"));
+    assert_eq!(
+        stream.try_pop(),
+        Err(Utf8StreamError::IndexOutOfRange {
+            index: 0,
+            length: 0
+        })
+    );
+}
+
+#[test]
+fn test_try_pop_reports_incomplete_tail() {
+    let mut stream = Utf8Stream::new("d");
+    stream.push_bytes(&[226]).unwrap();
+    assert_eq!(
+        stream.try_pop(),
+        Err(Utf8StreamError::IncompleteTail {
+            offset: 1,
+            bytes: vec![226]
+        })
+    );
+    assert_eq!(stream.pop().as_deref(), Some("d"));
+}
+
+#[test]
+fn test_try_as_str() {
+    let mut stream = Utf8Stream::new("red");
+    assert_eq!(stream.try_as_str(), Ok("red"));
+    stream.push_bytes(&[226, 157]).unwrap();
+    assert_eq!(
+        stream.try_as_str(),
+        Err(Utf8StreamError::IncompleteTail {
+            offset: 3,
+            bytes: vec![226, 157]
+        })
+    );
+    assert_eq!(stream.as_str(), "red");
+    stream.push_bytes(&[164]).unwrap();
+    assert_eq!(stream.try_as_str(), Ok("red❤"));
+}
+
+#[test]
+fn test_error_messages() {
+    assert_eq!(
+        Utf8StreamError::InvalidSequence {
+            offset: 3,
+            length: 2
+        }
+        .to_string(),
+        "invalid utf-8 sequence of 2 bytes at offset 3"
+    );
+    assert_eq!(
+        Utf8StreamError::IndexOutOfRange {
+            index: 20,
+            length: 14
+        }
+        .to_string(),
+        "byte index 20 is out of range for stream of 14 bytes"
+    );
+    assert_eq!(
+        Utf8StreamError::NotCharBoundary { index: 5 }.to_string(),
+        "byte index 5 is not a char boundary"
+    );
+    assert_eq!(
+        Utf8StreamError::AllocationFailure { size: 64 }.to_string(),
+        "failed to allocate 64 bytes"
+    );
+    assert_eq!(
+        Utf8StreamError::CapacityExceeded {
+            capacity: 8,
+            length: 14
+        }
+        .to_string(),
+        "14 bytes exceed the bounded stream capacity of 8 bytes"
+    );
+    assert_eq!(
+        Utf8StreamError::RecordTooLong {
+            limit: 8,
+            length: 14
+        }
+        .to_string(),
+        "record of 14 bytes exceeds the limit of 8 bytes"
+    );
+    assert_eq!(
+        Utf8StreamError::FrameTooLong {
+            limit: 8,
+            length: 14
+        }
+        .to_string(),
+        "frame of 14 bytes exceeds the limit of 8 bytes"
+    );
+    assert_eq!(
+        Utf8StreamError::MalformedFrame { offset: 3 }.to_string(),
+        "malformed frame at offset 3"
+    );
+}
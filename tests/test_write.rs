@@ -0,0 +1,58 @@
+use utf8_stream::Utf8Stream;
+
+#[test]
+fn test_io_write_macros() {
+    use std::io::Write as _;
+
+    let mut stream = Utf8Stream::default();
+    let heart = "❤️";
+    write!(stream, "red{heart}").unwrap();
+    writeln!(stream, "heart").unwrap();
+    assert_eq!(stream.as_str(), "red❤️heart\n");
+}
+
+#[test]
+fn test_fmt_write_macros() {
+    use std::fmt::Write as _;
+
+    let mut stream = Utf8Stream::new("fire");
+    let fire_fighter = "👩🏽‍🚒";
+    write!(stream, "{fire_fighter}fighter").unwrap();
+    assert_eq!(stream.as_str(), "fire👩🏽‍🚒fighter");
+}
+
+#[test]
+fn test_io_write_holds_back_incomplete_sequences() {
+    use std::io::Write as _;
+
+    let mut stream = Utf8Stream::default();
+    assert_eq!(stream.write(&[114, 101, 100, 226, 157]).unwrap(), 5);
+    assert_eq!(stream.as_str(), "red");
+    assert_eq!(stream.pending(), &[226, 157]);
+    stream.write_all(&[164, 239, 184, 143]).unwrap();
+    stream.flush().unwrap();
+    assert_eq!(stream.as_str(), "red❤️");
+    assert_eq!(stream.pending(), &[]);
+}
+
+#[test]
+fn test_io_copy() {
+    let mut stream = Utf8Stream::default();
+    let mut reader = "red❤️heart".as_bytes();
+    assert_eq!(std::io::copy(&mut reader, &mut stream).unwrap(), 14);
+    assert_eq!(
+        stream.collect::<Vec<String>>(),
+        vec!["r", "e", "d", "❤️", "h", "e", "a", "r", "t"]
+    );
+}
+
+#[test]
+fn test_push_after_incomplete_sequence() {
+    use std::io::Write as _;
+
+    let mut stream = Utf8Stream::default();
+    stream.write_all(&[114, 101, 100, 226, 157]).unwrap();
+    stream.push("heart");
+    assert_eq!(stream.as_str(), "red\u{fffd}heart");
+    assert_eq!(stream.pending(), &[]);
+}